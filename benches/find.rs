@@ -0,0 +1,34 @@
+//! Benchmark find_in() on a long ASCII string
+use charclass::{charclass, CharClass};
+use std::hint::black_box;
+use std::time::Instant;
+
+fn bench(name: &str, ccl: &CharClass, haystack: &str) {
+    let now = Instant::now();
+    let naive = haystack
+        .char_indices()
+        .find(|(_, ch)| ccl.contains(*ch))
+        .map(|(idx, _)| idx);
+    black_box(naive);
+    println!("{} char_indices(): {:?}", name, now.elapsed());
+
+    let now = Instant::now();
+    let found = ccl.find_in(haystack);
+    black_box(found);
+    println!("{} find_in():      {:?}", name, now.elapsed());
+
+    assert_eq!(naive, found);
+}
+
+fn main() {
+    let mut haystack = "lorem ipsum dolor sit amet ".repeat(400_000);
+    haystack.push_str("42!");
+
+    bench("[0-9]  ", &charclass!['0' => '9'], &haystack);
+    bench("[!]    ", &charclass!['!'], &haystack);
+    bench(
+        "[0-9€] ",
+        &(charclass!['0' => '9'] + charclass!['€']),
+        &haystack,
+    );
+}
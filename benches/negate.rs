@@ -0,0 +1,20 @@
+//! Benchmark negate() on a class with 10k ranges
+use charclass::CharClass;
+use std::hint::black_box;
+use std::time::Instant;
+
+fn main() {
+    let ccl = CharClass::from_sorted_chars((0..10_000u32).filter_map(|i| char::from_u32(i * 3)));
+    assert_eq!(ccl.range_count(), 10_000);
+
+    let now = Instant::now();
+    let negated = black_box(ccl.clone()).negate();
+    println!("negate():         {:?}", now.elapsed());
+
+    let now = Instant::now();
+    let difference = CharClass::any() - black_box(ccl.clone());
+    println!("any() - ccl:      {:?}", now.elapsed());
+
+    assert_eq!(negated, difference);
+    assert_eq!(negated.negate(), ccl);
+}
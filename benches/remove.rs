@@ -0,0 +1,30 @@
+//! Benchmark removing many characters from the middle of a large range
+use charclass::CharClass;
+use std::hint::black_box;
+use std::time::Instant;
+
+fn main() {
+    let mut ccl = CharClass::new();
+    ccl.add('\u{100}'..='\u{D7FF}');
+
+    let chars: Vec<char> = (0x200..0xd000)
+        .step_by(3)
+        .map(|cp| char::from_u32(cp).unwrap())
+        .collect();
+
+    let now = Instant::now();
+    let mut removed = ccl.clone();
+    for ch in &chars {
+        removed.remove_char(*ch);
+    }
+    black_box(&removed);
+    println!("remove_char():    {:?}", now.elapsed());
+
+    let now = Instant::now();
+    let mut subtracted = ccl.clone();
+    subtracted.subtract_chars(chars.iter().copied());
+    black_box(&subtracted);
+    println!("subtract_chars(): {:?}", now.elapsed());
+
+    assert_eq!(removed, subtracted);
+}
@@ -0,0 +1,35 @@
+//! Benchmark union_iter() against folding classes with `+`
+use charclass::CharClass;
+use std::hint::black_box;
+use std::time::Instant;
+
+fn main() {
+    let classes: Vec<CharClass> = (0..1000u32)
+        .map(|i| {
+            let mut ccl = CharClass::new();
+
+            for j in 0..20u32 {
+                let start = char::from_u32(i * 7 + j * 311).unwrap();
+                let end = char::from_u32(i * 7 + j * 311 + 3).unwrap();
+                ccl.add(start..=end);
+            }
+
+            ccl
+        })
+        .collect();
+
+    let now = Instant::now();
+    let folded = classes
+        .iter()
+        .cloned()
+        .fold(CharClass::new(), |acc, ccl| acc + ccl);
+    black_box(&folded);
+    println!("fold with +:  {:?}", now.elapsed());
+
+    let now = Instant::now();
+    let merged = CharClass::union_iter(&classes);
+    black_box(&merged);
+    println!("union_iter(): {:?}", now.elapsed());
+
+    assert_eq!(folded, merged);
+}
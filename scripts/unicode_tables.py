@@ -0,0 +1,123 @@
+#!/usr/bin/env python3
+"""
+Generates src/unicode/tables.rs from the Unicode Character Database.
+
+Usage: scripts/unicode_tables.py <ucd-directory> > src/unicode/tables.rs
+
+The directory must contain the plain UCD files, as available from
+https://www.unicode.org/Public/UCD/latest/ucd/
+"""
+import os
+import re
+import sys
+
+
+def read(ucd, filename):
+    """Read UCD file, returning its version and the (start, end, value) entries."""
+    entries = []
+    version = None
+
+    with open(os.path.join(ucd, filename), encoding="utf-8") as f:
+        for line in f:
+            if version is None:
+                match = re.match(r"#\s*\S+-(\d+\.\d+\.\d+)\.txt", line)
+                if match:
+                    version = match.group(1)
+
+            line = line.split("#", 1)[0].strip()
+            if not line:
+                continue
+
+            codepoints, value = [field.strip() for field in line.split(";")[:2]]
+            start, _, end = codepoints.partition("..")
+            entries.append((int(start, 16), int(end or start, 16), value))
+
+    return version, entries
+
+
+def merge(ranges):
+    """Sort and merge overlapping and adjacent ranges."""
+    merged = []
+
+    for start, end in sorted(ranges):
+        if merged and start <= merged[-1][1] + 1:
+            merged[-1][1] = max(merged[-1][1], end)
+        else:
+            merged.append([start, end])
+
+    return merged
+
+
+def property_ranges(entries, name):
+    return merge((start, end) for start, end, value in entries if value == name)
+
+
+def char(codepoint):
+    return "'\\u{%x}'" % codepoint
+
+
+def table(name, ranges):
+    lines = ["pub(crate) static %s: &[CharClassRange] = &[" % name]
+    lines.extend("    %s..=%s," % (char(start), char(end)) for start, end in ranges)
+    lines.append("];")
+    return "\n".join(lines)
+
+
+def main(ucd):
+    tables = []
+    versions = set()
+
+    version, entries = read(ucd, "DerivedCoreProperties.txt")
+    versions.add(version)
+
+    for name in ("XID_Start", "XID_Continue"):
+        tables.append(table(name.upper(), property_ranges(entries, name)))
+
+    version, entries = read(ucd, "PropList.txt")
+    versions.add(version)
+
+    tables.append(table("WHITE_SPACE", property_ranges(entries, "White_Space")))
+
+    version, entries = read(ucd, "Scripts.txt")
+    versions.add(version)
+
+    scripts = sorted(set(value for _, _, value in entries))
+
+    for name in scripts:
+        tables.append(table(name.upper(), property_ranges(entries, name)))
+
+    tables.append(
+        "\n".join(
+            ["pub(crate) static SCRIPTS: &[(&str, &[CharClassRange])] = &["]
+            + ['    ("%s", %s),' % (name, name.upper()) for name in scripts]
+            + ["];"]
+        )
+    )
+
+    version, entries = read(ucd, "Blocks.txt")
+    versions.add(version)
+
+    tables.append(
+        "\n".join(
+            ["pub(crate) static BLOCKS: &[(&str, u32, u32)] = &["]
+            + ['    ("%s", 0x%x, 0x%x),' % (name, start, end) for start, end, name in entries]
+            + ["];"]
+        )
+    )
+
+    if len(versions) != 1:
+        sys.exit("error: UCD files are from different versions: %s" % ", ".join(sorted(versions)))
+
+    print("//! Unicode tables, generated by scripts/unicode_tables.py")
+    print("//!")
+    print("//! DO NOT EDIT THIS FILE MANUALLY.")
+    print("//! Unicode version: %s" % versions.pop())
+    print("use crate::CharClassRange;")
+
+    for tab in tables:
+        print()
+        print(tab)
+
+
+if __name__ == "__main__":
+    main(sys.argv[1])
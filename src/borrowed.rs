@@ -0,0 +1,93 @@
+//! Borrowed character-classes
+use super::{range_len, CharClass, CharClassRange};
+
+/** Read-only character class borrowing its ranges.
+
+As a `CharClass` owns its ranges in a `Vec`, it can't be constructed in a
+constant context. A `CharClassRef` instead wraps a slice of ranges, so
+generated code can emit a `static` table of ranges and query it directly,
+without any allocation at runtime.
+
+The ranges must be normalized, meaning sorted, non-overlapping and not
+adjacent, as produced by `CharClass::ranges()`.
+
+Example:
+```
+use charclass::CharClassRef;
+
+static IDENT: CharClassRef = CharClassRef::new(&['0'..='9', 'A'..='Z', '_'..='_', 'a'..='z']);
+
+assert!(IDENT.contains('_'));
+assert!(IDENT.test(&('a'..='f')));
+assert!(!IDENT.contains('-'));
+assert_eq!(IDENT.len(), 63);
+```
+*/
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct CharClassRef<'a> {
+    ranges: &'a [CharClassRange],
+}
+
+impl<'a> CharClassRef<'a> {
+    /** Create borrowed character class from a slice of normalized ranges.
+
+    As a `const fn`, this can initialize a `static` from a static slice.
+    */
+    pub const fn new(ranges: &'a [CharClassRange]) -> Self {
+        Self { ranges }
+    }
+
+    /** Get the character ranges as a slice. */
+    pub fn ranges(&self) -> &'a [CharClassRange] {
+        self.ranges
+    }
+
+    /** Retrieve total number of characters in class */
+    pub fn len(&self) -> u32 {
+        self.ranges.iter().map(range_len).sum()
+    }
+
+    /** Check if character class is empty */
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /** Test if a character is part of the character class. */
+    pub fn contains(&self, ch: char) -> bool {
+        let idx = self.ranges.partition_point(|r| *r.start() <= ch);
+        idx > 0 && *self.ranges[idx - 1].end() >= ch
+    }
+
+    /** Test if the entire range is part of the character class.
+
+    An empty (reversed) range is never contained.
+    */
+    pub fn test(&self, range: &CharClassRange) -> bool {
+        if range.is_empty() {
+            return false;
+        }
+
+        // Find the last range starting at or before the query's start
+        let idx = self.ranges.partition_point(|r| r.start() <= range.start());
+
+        if idx == 0 {
+            return false;
+        }
+
+        let end = *self.ranges[idx - 1].end();
+
+        // The range may span the surrogate gap between two ranges
+        end >= *range.end()
+            || (end == '\u{D7FF}'
+                && self
+                    .ranges
+                    .get(idx)
+                    .is_some_and(|next| *next.start() == '\u{E000}' && next.end() >= range.end()))
+    }
+}
+
+impl From<CharClassRef<'_>> for CharClass {
+    fn from(ccl: CharClassRef<'_>) -> Self {
+        CharClass::from_vec(ccl.ranges.to_vec())
+    }
+}
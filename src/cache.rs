@@ -0,0 +1,34 @@
+//! Lazily built lookup cache of character-classes
+use std::sync::OnceLock;
+
+/** Bitmap of the ASCII members of a class, built on first use.
+
+The cache doesn't take part in comparing or hashing classes, as it only
+mirrors their ranges.
+*/
+#[derive(Clone, Default)]
+pub(crate) struct AsciiCache(OnceLock<[u64; 2]>);
+
+impl AsciiCache {
+    /** Get the bitmap, building it using `init` when not cached yet. */
+    pub(crate) fn get_or_init(&self, init: impl FnOnce() -> [u64; 2]) -> &[u64; 2] {
+        self.0.get_or_init(init)
+    }
+
+    /** Drop the bitmap, so it is rebuilt on next use. */
+    pub(crate) fn invalidate(&mut self) {
+        self.0.take();
+    }
+}
+
+impl PartialEq for AsciiCache {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for AsciiCache {}
+
+impl std::hash::Hash for AsciiCache {
+    fn hash<H: std::hash::Hasher>(&self, _: &mut H) {}
+}
@@ -0,0 +1,94 @@
+//! Compiled character-classes for fast lookup
+use super::{CharClass, CharClassRange, CharClassRef};
+
+/** Character class compiled for fast membership tests.
+
+Membership for the Latin-1 range is looked up in a 256-bit bitmap,
+other characters are found by binary search over the remaining ranges.
+*/
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompiledCharClass {
+    latin1: [u64; 4],
+    ranges: Vec<CharClassRange>,
+}
+
+impl CompiledCharClass {
+    /** Test if a character is part of the compiled class. */
+    pub fn contains(&self, ch: char) -> bool {
+        if (ch as u32) < 0x100 {
+            return self.contains_byte(ch as u8);
+        }
+
+        CharClassRef::new(&self.ranges).contains(ch)
+    }
+
+    /** Test if a byte, taken as Latin-1 character, is part of the compiled class. */
+    pub fn contains_byte(&self, b: u8) -> bool {
+        self.latin1[(b >> 6) as usize] & (1 << (b & 63)) != 0
+    }
+}
+
+impl CharClass {
+    /** Compile character class into a structure for fast membership tests.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let ccl = charclass!['a' => 'z', '€' => '€'].compile();
+    assert!(ccl.contains('q'));
+    assert!(ccl.contains('€'));
+    assert!(!ccl.contains('A'));
+    ```
+    */
+    pub fn compile(&self) -> CompiledCharClass {
+        let ranges = self
+            .ranges
+            .iter()
+            .filter(|r| *r.end() as u32 >= 0x100)
+            .map(|r| (*r.start()).max('\u{100}')..=*r.end())
+            .collect();
+
+        CompiledCharClass {
+            latin1: self.to_bitset_256(),
+            ranges,
+        }
+    }
+
+    /** Compile character class into a case-insensitive structure for fast membership tests.
+
+    Every character is extended by its single-character lower- and uppercase
+    forms before compilation.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let ccl = charclass!['a' => 'z', 'ä' => 'ä'].compile_case_insensitive();
+    assert!(ccl.contains('a'));
+    assert!(ccl.contains('A'));
+    assert!(ccl.contains('Ä'));
+    assert!(!ccl.contains('Ö'));
+    ```
+    */
+    pub fn compile_case_insensitive(&self) -> CompiledCharClass {
+        let mut chars = Vec::new();
+
+        for ch in self.ranges.iter().flat_map(|r| r.clone()) {
+            let mut lower = ch.to_lowercase();
+            let mut upper = ch.to_uppercase();
+
+            if let (Some(cased), None) = (lower.next(), lower.next()) {
+                chars.push(cased);
+            }
+
+            if let (Some(cased), None) = (upper.next(), upper.next()) {
+                chars.push(cased);
+            }
+        }
+
+        chars.sort_unstable();
+
+        (CharClass::from_sorted_chars(chars) + self.clone()).compile()
+    }
+}
@@ -0,0 +1,77 @@
+//! Interning of character-classes
+use super::CharClass;
+use std::collections::HashMap;
+
+/** Identifier of a class interned by a `CharClassInterner`. */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClassId(u32);
+
+impl ClassId {
+    /** Index of the class in the order of interning, starting at 0. */
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/** Deduplicates structurally equal character classes.
+
+Every distinct class is stored once and gets a small, stable `ClassId`.
+As classes are kept normalized, equal classes always get the same id.
+
+Example:
+```
+use charclass::{charclass, CharClassInterner};
+
+let mut interner = CharClassInterner::new();
+let a = interner.intern(charclass!['a' => 'z']);
+let b = interner.intern(charclass!['0' => '9']);
+let c = interner.intern(charclass!['a' => 'm'] + charclass!['n' => 'z']);
+
+assert_eq!(a, c);
+assert_ne!(a, b);
+assert_eq!(interner.len(), 2);
+assert_eq!(interner.get(b), &charclass!['0' => '9']);
+```
+*/
+#[derive(Clone, Debug, Default)]
+pub struct CharClassInterner {
+    classes: Vec<CharClass>,
+    /// Ids of the interned classes by their `stable_hash()`, so each class is only stored once
+    ids: HashMap<u64, Vec<ClassId>>,
+}
+
+impl CharClassInterner {
+    /** Create a new, empty interner. */
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /** Intern a class, returning the id of an equal class interned before, or a new id. */
+    pub fn intern(&mut self, ccl: CharClass) -> ClassId {
+        let candidates = self.ids.entry(ccl.stable_hash()).or_default();
+
+        if let Some(id) = candidates.iter().find(|id| self.classes[id.index()] == ccl) {
+            return *id;
+        }
+
+        let id = ClassId(u32::try_from(self.classes.len()).expect("Too many interned classes"));
+        self.classes.push(ccl);
+        candidates.push(id);
+        id
+    }
+
+    /** Get the class of an id returned by `intern()`. */
+    pub fn get(&self, id: ClassId) -> &CharClass {
+        &self.classes[id.index()]
+    }
+
+    /** Number of distinct interned classes. */
+    pub fn len(&self) -> usize {
+        self.classes.len()
+    }
+
+    /** Check if no class was interned yet. */
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+}
@@ -1,158 +1,2823 @@
 //! Character-classes
+mod borrowed;
+#[cfg(feature = "cache")]
+mod cache;
+mod compiled;
+mod interner;
+mod parse;
+mod ranges_mut;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "unicode")]
+mod unicode;
+
+pub use borrowed::CharClassRef;
+pub use compiled::CompiledCharClass;
+pub use interner::{CharClassInterner, ClassId};
+pub use parse::ParseError;
+pub use ranges_mut::RangesMut;
+
 type CharClassRange = std::ops::RangeInclusive<char>;
 
-/// Representation of a character-class
-#[derive(Clone, Hash, Eq, PartialEq)]
-pub struct CharClass {
-    ranges: Vec<CharClassRange>,
-}
+#[cfg(test)]
+thread_local! {
+    /** Number of `normalize()` calls, to check that fast paths avoid it. */
+    static NORMALIZE_CALLS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    /** Number of ranges visited by `len_at_least()`, to check its early exit. */
+    static LEN_AT_LEAST_VISITS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/** Get the next valid character after `ch`, skipping the surrogate gap. */
+fn next_char(ch: char) -> Option<char> {
+    match ch {
+        '\u{D7FF}' => Some('\u{E000}'),
+        std::char::MAX => None,
+        _ => std::char::from_u32(ch as u32 + 1),
+    }
+}
+
+/** Get the previous valid character before `ch`, skipping the surrogate gap. */
+fn prev_char(ch: char) -> Option<char> {
+    match ch {
+        '\u{E000}' => Some('\u{D7FF}'),
+        '\0' => None,
+        _ => std::char::from_u32(ch as u32 - 1),
+    }
+}
+
+/** Split a range of code points into the valid character ranges around the surrogate gap.
+
+Yields no range when the code points are entirely within the surrogate gap,
+and two ranges when they span it. Code points beyond `char::MAX` are ignored.
+
+Example:
+```
+use charclass::valid_char_ranges;
+
+assert_eq!(valid_char_ranges(0x61, 0x7a).collect::<Vec<_>>(), vec!['a'..='z']);
+assert_eq!(
+    valid_char_ranges(0xe000, 0xe0ff).collect::<Vec<_>>(),
+    vec!['\u{E000}'..='\u{E0FF}']
+);
+assert_eq!(
+    valid_char_ranges(0xd7f0, 0xe00f).collect::<Vec<_>>(),
+    vec!['\u{D7F0}'..='\u{D7FF}', '\u{E000}'..='\u{E00F}']
+);
+assert_eq!(valid_char_ranges(0xd800, 0xdfff).count(), 0);
+```
+*/
+pub fn valid_char_ranges(start: u32, end: u32) -> impl Iterator<Item = CharClassRange> {
+    let end = end.min(std::char::MAX as u32);
+
+    [(start, end.min(0xd7ff)), (start.max(0xe000), end)]
+        .into_iter()
+        .filter(|(start, end)| start <= end)
+        .map(|(start, end)| std::char::from_u32(start).unwrap()..=std::char::from_u32(end).unwrap())
+}
+
+/** Count the valid characters of a range, excluding any surrogates. */
+fn range_len(range: &CharClassRange) -> u32 {
+    if range.start() > range.end() {
+        return 0;
+    }
+
+    let start = *range.start() as u32;
+    let end = *range.end() as u32;
+    let mut len = end - start + 1;
+
+    if start <= 0xdfff && end >= 0xd800 {
+        len -= end.min(0xdfff) - start.max(0xd800) + 1;
+    }
+
+    len
+}
+
+/** Check if `b` directly follows `a`.
+
+The surrogate gap is never bridged, so `'\u{D7FF}'` and `'\u{E000}'` are
+not adjacent, and ranges ending and starting there are kept separate.
+*/
+fn is_adjacent(a: char, b: char) -> bool {
+    a as u32 + 1 == b as u32
+}
+
+/** Iterate over the overlaps of two sorted, normalized range lists. */
+fn overlapping_ranges<'a>(
+    a: &'a [CharClassRange],
+    b: &'a [CharClassRange],
+) -> impl Iterator<Item = CharClassRange> + 'a {
+    let (mut i, mut j) = (0, 0);
+
+    std::iter::from_fn(move || {
+        while i < a.len() && j < b.len() {
+            let start = *a[i].start().max(b[j].start());
+            let end = *a[i].end().min(b[j].end());
+
+            // Advance the range ending first, the other one may overlap further
+            if a[i].end() < b[j].end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+
+            if start <= end {
+                return Some(start..=end);
+            }
+        }
+
+        None
+    })
+}
+
+/** Intersect two sorted, normalized range lists into `out`, which is cleared before. */
+fn intersect_ranges(a: &[CharClassRange], b: &[CharClassRange], out: &mut Vec<CharClassRange>) {
+    out.clear();
+    out.extend(overlapping_ranges(a, b));
+}
+
+/** Turn any range bounds into an inclusive range, or `None` if it's empty. */
+fn bounds_to_range<R: std::ops::RangeBounds<char>>(range: R) -> Option<CharClassRange> {
+    use std::ops::Bound;
+
+    let start = match range.start_bound() {
+        Bound::Included(ch) => *ch,
+        Bound::Excluded(ch) => next_char(*ch)?,
+        Bound::Unbounded => '\0',
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(ch) => *ch,
+        Bound::Excluded(ch) => prev_char(*ch)?,
+        Bound::Unbounded => std::char::MAX,
+    };
+
+    if start <= end {
+        Some(start..=end)
+    } else {
+        None
+    }
+}
+
+/// Error on invalid character-class operations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CharClassError {
+    /// A code point beyond `char::MAX`
+    InvalidCodePoint(u32),
+    /// A range where start is greater than end
+    ReversedRange(u32, u32),
+    /// A range including surrogate code points, which aren't valid characters
+    SurrogateRange(u32, u32),
+}
+
+impl std::fmt::Display for CharClassError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidCodePoint(cp) => write!(f, "Invalid code point {:#x}", cp),
+            Self::ReversedRange(start, end) => {
+                write!(
+                    f,
+                    "Invalid range {:#x}-{:#x}, start is greater than end",
+                    start, end
+                )
+            }
+            Self::SurrogateRange(start, end) => {
+                write!(
+                    f,
+                    "Invalid range {:#x}-{:#x}, includes surrogate code points",
+                    start, end
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for CharClassError {}
+
+/// Outcome of adding a range to a character-class
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddOutcome {
+    /// None of the characters were part of the class before
+    New,
+    /// Some characters were part of the class before, holding their number
+    Overlapping(u32),
+    /// All characters were part of the class before
+    Redundant,
+}
+
+/// Statistics of a character-class, as returned by `CharClass::stats()`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassStats {
+    /// Number of characters
+    pub char_count: u32,
+    /// Number of ranges
+    pub range_count: usize,
+    /// Smallest character, if any
+    pub min: Option<char>,
+    /// Largest character, if any
+    pub max: Option<char>,
+    /// Number of valid characters from `min` to `max`
+    pub span: u32,
+    /// Ratio of `char_count` to `span`, or 0.0 for an empty class
+    pub density: f64,
+    /// The range holding the most characters, the first one on a tie
+    pub largest_range: Option<CharClassRange>,
+}
+
+/// Suggested representation of a character-class for matching, see `CharClass::suggest_representation()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Representation {
+    /// No character matches
+    Empty,
+    /// Every character matches
+    Full,
+    /// Compare against a single character
+    SingleChar,
+    /// Compare against the bounds of a single range
+    SingleRange,
+    /// Look up the characters in a bitmap over the class's span
+    Bitmap,
+    /// Search the sorted ranges
+    Ranges,
+}
+
+/// Representation of a character-class
+#[derive(Clone, Hash, Eq, PartialEq)]
+pub struct CharClass {
+    ranges: Vec<CharClassRange>,
+    #[cfg(feature = "cache")]
+    cache: cache::AsciiCache,
+}
+
+impl CharClass {
+    /** Number of valid Unicode scalar values, which is all code points except the surrogates.
+
+    Example:
+    ```
+    use charclass::CharClass;
+
+    assert_eq!(CharClass::any().len(), CharClass::SCALAR_VALUE_COUNT);
+    assert_eq!(CharClass::SCALAR_VALUE_COUNT, 0x110000 - 0x800);
+    ```
+    */
+    pub const SCALAR_VALUE_COUNT: u32 = 1_112_064;
+
+    /** Create character class from ranges, which are taken as they are. */
+    fn from_vec(ranges: Vec<CharClassRange>) -> Self {
+        Self {
+            ranges,
+            #[cfg(feature = "cache")]
+            cache: Default::default(),
+        }
+    }
+
+    /** Invalidate any cached lookup data, after the ranges were changed. */
+    fn invalidate(&mut self) {
+        #[cfg(feature = "cache")]
+        self.cache.invalidate();
+    }
+
+    /** Create new empty character class. */
+    pub fn new() -> Self {
+        Self::from_vec(Vec::new())
+    }
+
+    /** Create character class holding exactly one character.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let ccl = CharClass::single('x');
+    assert_eq!(ccl, charclass!['x']);
+    assert_eq!(ccl.len(), 1);
+    assert_eq!(ccl.as_single_char(), Some('x'));
+    assert_eq!(charclass!['x', 'y'].as_single_char(), None);
+    ```
+    */
+    pub fn single(ch: char) -> Self {
+        Self::from_vec(vec![ch..=ch])
+    }
+
+    /** Create character class holding the single range `start..=end`.
+
+    A reversed range, where `start` is greater than `end`, results in an
+    empty class. A range spanning the surrogate gap is split around it.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    assert_eq!(CharClass::range('a', 'z'), charclass!['a' => 'z']);
+    assert!(CharClass::range('z', 'a').is_empty());
+    assert_eq!(CharClass::range('\u{D000}', '\u{E100}').range_count(), 2);
+    ```
+    */
+    pub fn range(start: char, end: char) -> Self {
+        Self::from_vec(valid_char_ranges(start as u32, end as u32).collect())
+    }
+
+    /** Get the only character of the class, if it holds exactly one. */
+    pub fn as_single_char(&self) -> Option<char> {
+        match self.ranges.as_slice() {
+            [range] if range.start() == range.end() => Some(*range.start()),
+            _ => None,
+        }
+    }
+
+    /** Create character class from characters given in ascending order.
+
+    Consecutive characters are coalesced into ranges in a single pass,
+    without the need for a full normalization.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let ccl = CharClass::from_sorted_chars(['a', 'b', 'c', 'd', 'e']);
+    assert_eq!(ccl, charclass!['a' => 'e']);
+    assert_eq!(ccl.ranges().len(), 1);
+    ```
+    */
+    pub fn from_sorted_chars<I: IntoIterator<Item = char>>(iter: I) -> CharClass {
+        let mut ranges: Vec<CharClassRange> = Vec::new();
+
+        for ch in iter {
+            if let Some(last) = ranges.last_mut() {
+                debug_assert!(ch >= *last.end(), "characters must be sorted");
+
+                if ch <= *last.end() {
+                    continue;
+                } else if is_adjacent(*last.end(), ch) {
+                    *last = *last.start()..=ch;
+                    continue;
+                }
+            }
+
+            ranges.push(ch..=ch);
+        }
+
+        CharClass::from_vec(ranges)
+    }
+
+    /** Create character class from a slice of characters in any order.
+
+    The characters are sorted and coalesced into ranges once.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let ops = CharClass::from_chars(&['+', '-', '*', '/']);
+    assert_eq!(ops.ranges(), &['*'..='+', '-'..='-', '/'..='/']);
+    assert_eq!(CharClass::from_chars(&['c', 'a', 'b', 'a']), charclass!['a' => 'c']);
+    ```
+    */
+    pub fn from_chars(chars: &[char]) -> CharClass {
+        let mut chars = chars.to_vec();
+        chars.sort_unstable();
+        CharClass::from_sorted_chars(chars)
+    }
+
+    /** Create character class from all characters within `range` matching a predicate.
+
+    Only the given window is scanned, skipping the surrogate gap.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let vowels = CharClass::from_predicate_in('a'..='z', |ch| "aeiou".contains(ch));
+    assert_eq!(vowels, charclass!['a', 'e', 'i', 'o', 'u']);
+
+    let greek = CharClass::from_predicate_in('\u{370}'..='\u{3FF}', char::is_uppercase);
+    assert!(greek.contains('Ω'));
+    assert!(!greek.contains('ω'));
+    ```
+    */
+    pub fn from_predicate_in<F: Fn(char) -> bool>(range: CharClassRange, f: F) -> CharClass {
+        CharClass::from_sorted_chars(range.filter(|ch| f(*ch)))
+    }
+
+    /** Create character class from raw ranges, without any normalization.
+
+    The caller is responsible that the ranges are sorted, non-overlapping
+    and valid; otherwise, operations on the class may return wrong results.
+    In debug builds, the operations relying on normalized input without
+    normalizing it first trip an assertion on such a class: `negate()`,
+    `extend_sorted()`, `clamp()`, and the operators `-`, `&` and `^`.
+    Calling `add()`, `+=`, `normalize()` or `canonicalize()` repairs the class.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let mut ccl = CharClass::from_ranges_unchecked(vec!['x'..='z', 'a'..='z']);
+    ccl.add('0'..='9');
+    assert_eq!(ccl, charclass!['0' => '9', 'a' => 'z']);
+    ```
+    */
+    pub fn from_ranges_unchecked(ranges: Vec<CharClassRange>) -> CharClass {
+        CharClass::from_vec(ranges)
+    }
+
+    /** Create character class from all characters of a string, taken literally.
+
+    In contrast to bracket expressions, no character has a special meaning,
+    including `-`, `]`, `^` and `\\`.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let ccl = CharClass::parse_escaped("a-z");
+    assert_eq!(ccl, charclass!['-', 'a', 'z']);
+    assert_eq!(ccl.len(), 3);
+    ```
+    */
+    pub fn parse_escaped(s: &str) -> CharClass {
+        let mut chars: Vec<char> = s.chars().collect();
+        chars.sort_unstable();
+
+        CharClass::from_sorted_chars(chars)
+    }
+
+    /** Create character class from a set of characters.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+    use std::collections::BTreeSet;
+
+    let set: BTreeSet<char> = ['a', 'b', 'c', 'x'].into_iter().collect();
+    let ccl = CharClass::from_set(&set);
+    assert_eq!(ccl, charclass!['a' => 'c', 'x' => 'x']);
+    assert_eq!(ccl.to_set(100), Some(set));
+    assert_eq!(ccl.to_set(3), None);
+    ```
+    */
+    pub fn from_set(set: &std::collections::BTreeSet<char>) -> CharClass {
+        CharClass::from_sorted_chars(set.iter().copied())
+    }
+
+    /** Create character class from a 256-bit mask of Latin-1 characters.
+
+    Bit `n % 64` of `bits[n / 64]` stands for the character with code point `n`.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let ccl = CharClass::from_bitset_256(&[0x03ff_0000_0000_0000, 0x07ff_fffe, 0, 1]);
+    assert_eq!(ccl, charclass!['0' => '9', 'A' => 'Z', '\u{C0}' => '\u{C0}']);
+    ```
+    */
+    pub fn from_bitset_256(bits: &[u64; 4]) -> CharClass {
+        CharClass::from_sorted_chars(
+            (0..=255u8)
+                .filter(|b| bits[(b >> 6) as usize] & (1 << (b & 63)) != 0)
+                .map(char::from),
+        )
+    }
+
+    /** Create character class from pairs of code points, collecting all errors.
+
+    In contrast to adding the pairs one by one with `add_u32_range()`, every
+    invalid pair is reported, instead of failing on the first one.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass, CharClassError};
+
+    assert_eq!(
+        CharClass::from_pairs_checked(&[(0x61, 0x7a), (0x30, 0x39)]),
+        Ok(charclass!['0' => '9', 'a' => 'z'])
+    );
+    assert_eq!(
+        CharClass::from_pairs_checked(&[(0x7a, 0x61), (0x30, 0x39), (0x41, 0x110000)]),
+        Err(vec![
+            CharClassError::ReversedRange(0x7a, 0x61),
+            CharClassError::InvalidCodePoint(0x110000)
+        ])
+    );
+    assert_eq!(
+        CharClass::from_pairs_checked(&[(0xd700, 0xe0ff)]),
+        Ok(charclass!['\u{D700}' => '\u{D7FF}', '\u{E000}' => '\u{E0FF}'])
+    );
+    assert_eq!(
+        CharClass::from_pairs_checked(&[(0xd800, 0xdbff)]),
+        Err(vec![CharClassError::SurrogateRange(0xd800, 0xdbff)])
+    );
+    ```
+    */
+    pub fn from_pairs_checked(pairs: &[(u32, u32)]) -> Result<CharClass, Vec<CharClassError>> {
+        let mut ccl = CharClass::new();
+        let mut errors = Vec::new();
+
+        for &(start, end) in pairs {
+            if let Err(error) = ccl.add_u32_range(start, end) {
+                errors.push(error);
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(ccl)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /** Create the union of many character classes at once.
+
+    Performs a k-way merge over the ranges of all classes, which is more
+    efficient than folding them together using `+`.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let classes: Vec<CharClass> = (0..50u32)
+        .map(|i| {
+            let ch = char::from_u32('a' as u32 + i * 2).unwrap();
+            charclass![ch]
+        })
+        .collect();
+
+    let ccl = CharClass::union_iter(&classes);
+    assert_eq!(ccl.len(), 50);
+    assert_eq!(ccl, classes.into_iter().fold(CharClass::new(), |acc, c| acc + c));
+    ```
+    */
+    pub fn union_iter<'a, I: IntoIterator<Item = &'a CharClass>>(classes: I) -> CharClass {
+        let classes: Vec<&CharClass> = classes.into_iter().collect();
+
+        CharClass::from_vec(Self::merge_ranges(&classes))
+    }
+
+    /** Create the union of many character classes, returning just its ranges.
+
+    This is the k-way merge of `union_iter()`, without the class wrapping the
+    result, e.g. when only the ranges are needed for code generation.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let classes = [charclass!['a' => 'f'], charclass!['0' => '9'], charclass!['d' => 'k']];
+    let ranges = CharClass::union_ranges(&classes);
+    assert_eq!(ranges, vec!['0'..='9', 'a'..='k']);
+    assert_eq!(ranges, CharClass::union_iter(&classes).ranges());
+    assert!(CharClass::union_ranges(&[]).is_empty());
+    ```
+    */
+    pub fn union_ranges(classes: &[CharClass]) -> Vec<CharClassRange> {
+        Self::merge_ranges(&classes.iter().collect::<Vec<_>>())
+    }
+
+    /** Create the intersection of many character classes at once.
+
+    A running intersection is computed in two reused buffers, stopping early
+    as soon as it becomes empty. The intersection of no classes at all is
+    the class matching any character.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let classes = [
+        charclass!['a' => 'p'],
+        charclass!['f' => 'z'],
+        charclass!['0' => '9', 'k' => 'x'],
+    ];
+    assert_eq!(CharClass::intersect_iter(&classes), charclass!['k' => 'p']);
+
+    let classes = [charclass!['a' => 'c'], charclass!['x' => 'z'], charclass!['a' => 'z']];
+    assert!(CharClass::intersect_iter(&classes).is_empty());
+
+    assert!(CharClass::intersect_iter(&[]).is_any());
+    ```
+    */
+    pub fn intersect_iter<'a, I: IntoIterator<Item = &'a CharClass>>(iter: I) -> CharClass {
+        let mut iter = iter.into_iter();
+
+        let mut ranges = match iter.next() {
+            Some(first) => first.ranges.clone(),
+            None => return CharClass::any(),
+        };
+        let mut buf = Vec::with_capacity(ranges.len());
+
+        for ccl in iter {
+            if ranges.is_empty() {
+                break;
+            }
+
+            intersect_ranges(&ranges, &ccl.ranges, &mut buf);
+            std::mem::swap(&mut ranges, &mut buf);
+        }
+
+        CharClass::from_vec(ranges)
+    }
+
+    /** Merge the sorted ranges of many classes into one sorted, normalized list. */
+    fn merge_ranges(classes: &[&CharClass]) -> Vec<CharClassRange> {
+        use std::cmp::Reverse;
+
+        let mut heap = std::collections::BinaryHeap::with_capacity(classes.len());
+
+        for (i, ccl) in classes.iter().enumerate() {
+            if let Some(range) = ccl.ranges.first() {
+                heap.push(Reverse((*range.start(), i, 0)));
+            }
+        }
+
+        let mut ranges: Vec<CharClassRange> = Vec::new();
+
+        while let Some(Reverse((_, i, j))) = heap.pop() {
+            let range = &classes[i].ranges[j];
+
+            if let Some(next) = classes[i].ranges.get(j + 1) {
+                heap.push(Reverse((*next.start(), i, j + 1)));
+            }
+
+            if let Some(last) = ranges.last_mut() {
+                if range.start() <= last.end() || is_adjacent(*last.end(), *range.start()) {
+                    if range.end() > last.end() {
+                        *last = *last.start()..=*range.end();
+                    }
+
+                    continue;
+                }
+            }
+
+            ranges.push(range.clone());
+        }
+
+        ranges
+    }
+
+    /** Create character class matching any character.
+
+    The canonical form holds two ranges, split around the surrogate gap.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let any = CharClass::any();
+    assert_eq!(any.ranges(), &['\0'..='\u{D7FF}', '\u{E000}'..=char::MAX]);
+    assert_eq!(any, charclass!['\0' => char::MAX]);
+    assert_eq!(any, CharClass::new().negate());
+    assert_eq!(any.len(), 0x110000 - 0x800);
+    ```
+    */
+    pub fn any() -> Self {
+        Self::from_vec(vec!['\0'..='\u{D7FF}', '\u{E000}'..=std::char::MAX])
+    }
+
+    /** Create character class of ASCII letters, `[A-Za-z]`. */
+    pub fn ascii_letters() -> Self {
+        Self::from_vec(vec!['A'..='Z', 'a'..='z'])
+    }
+
+    /** Create character class of ASCII digits, `[0-9]`. */
+    pub fn ascii_digits() -> Self {
+        Self::from_vec(vec!['0'..='9'])
+    }
+
+    /** Create character class of ASCII letters and digits, `[0-9A-Za-z]`. */
+    pub fn ascii_alphanumeric() -> Self {
+        Self::from_vec(vec!['0'..='9', 'A'..='Z', 'a'..='z'])
+    }
+
+    /** Create character class of ASCII whitespace, `[\t\n\f\r ]`.
+
+    This follows `char::is_ascii_whitespace()`, so the vertical tab is not included.
+    */
+    pub fn ascii_whitespace() -> Self {
+        Self::from_vec(vec!['\t'..='\n', '\x0c'..='\r', ' '..=' '])
+    }
+
+    /** Create character class of characters starting an identifier, `[A-Z_a-z]`. */
+    pub fn identifier_start() -> Self {
+        Self::from_vec(vec!['A'..='Z', '_'..='_', 'a'..='z'])
+    }
+
+    /** Create character class of characters continuing an identifier, `[0-9A-Z_a-z]`.
+
+    Example:
+    ```
+    use charclass::CharClass;
+
+    let ccl = CharClass::identifier_continue();
+    assert_eq!(ccl.ranges(), &['0'..='9', 'A'..='Z', '_'..='_', 'a'..='z']);
+    assert_eq!(ccl, CharClass::ascii_alphanumeric() + CharClass::identifier_start());
+    assert_eq!(
+        CharClass::ascii_alphanumeric(),
+        CharClass::ascii_letters() + CharClass::ascii_digits()
+    );
+
+    for ch in '\0'..='\x7f' {
+        assert_eq!(CharClass::ascii_letters().contains(ch), ch.is_ascii_alphabetic());
+        assert_eq!(CharClass::ascii_digits().contains(ch), ch.is_ascii_digit());
+        assert_eq!(CharClass::ascii_whitespace().contains(ch), ch.is_ascii_whitespace());
+    }
+    ```
+    */
+    pub fn identifier_continue() -> Self {
+        Self::from_vec(vec!['0'..='9', 'A'..='Z', '_'..='_', 'a'..='z'])
+    }
+
+    /** Merge entries of the same action whose classes overlap or are adjacent.
+
+    This compresses tables mapping character classes to actions, like the
+    transitions of a lexer. The order of the first occurrence of each merged
+    entry is kept.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let merged = CharClass::coalesce_by_action(vec![
+        (charclass!['a' => 'f'], 1),
+        (charclass!['0' => '9'], 2),
+        (charclass!['g' => 'z'], 1),
+        (charclass!['A' => 'Z'], 1),
+    ]);
+
+    assert_eq!(
+        merged,
+        vec![
+            (charclass!['a' => 'z'], 1),
+            (charclass!['0' => '9'], 2),
+            (charclass!['A' => 'Z'], 1)
+        ]
+    );
+    ```
+    */
+    pub fn coalesce_by_action<T: Eq>(entries: Vec<(CharClass, T)>) -> Vec<(CharClass, T)> {
+        // Classes touch when their union needs fewer ranges than both together
+        fn touches(a: &CharClass, b: &CharClass) -> bool {
+            (a.clone() + b.clone()).ranges.len() < a.ranges.len() + b.ranges.len()
+        }
+
+        let mut result: Vec<(CharClass, T)> = Vec::with_capacity(entries.len());
+
+        for (ccl, action) in entries {
+            let i = match result
+                .iter()
+                .position(|(other, other_action)| *other_action == action && touches(other, &ccl))
+            {
+                Some(i) => i,
+                None => {
+                    result.push((ccl, action));
+                    continue;
+                }
+            };
+
+            result[i].0 += ccl;
+
+            // Absorb any following entries which now touch the grown class
+            let mut j = i + 1;
+
+            while j < result.len() {
+                if result[j].1 == result[i].1 && touches(&result[i].0, &result[j].0) {
+                    let (other, _) = result.remove(j);
+                    result[i].0 += other;
+                    j = i + 1;
+                } else {
+                    j += 1;
+                }
+            }
+        }
+
+        result
+    }
+
+    /** Retrieve total number of characters in class
+
+    This is the number of characters, not of ranges; see `range_count()` for the
+    latter. Even the class of all characters fits into an `u32`.
+    */
+    pub fn len(&self) -> u32 {
+        self.ranges.iter().map(range_len).sum()
+    }
+
+    /** Retrieve total number of characters in class as `u64`.
+
+    This provides headroom when summing up the sizes of many classes.
+
+    Example:
+    ```
+    use charclass::CharClass;
+
+    let any = CharClass::any();
+    assert_eq!(any.len(), 1_112_064);
+    assert_eq!(any.count(), 1_112_064u64);
+    assert_eq!(any.range_count(), 2);
+    ```
+    */
+    pub fn count(&self) -> u64 {
+        self.len() as u64
+    }
+
+    /** Check if the class holds at least `n` characters.
+
+    Counting stops at the first range where `n` is reached, so this is cheaper
+    than `len() >= n` on classes with many ranges.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let ccl = charclass!['a' => 'z', '0' => '9'];
+    assert!(ccl.len_at_least(0));
+    assert!(ccl.len_at_least(10));
+    assert!(ccl.len_at_least(36));
+    assert!(!ccl.len_at_least(37));
+    assert!(CharClass::any().len_at_least(CharClass::SCALAR_VALUE_COUNT));
+    ```
+    */
+    pub fn len_at_least(&self, n: u32) -> bool {
+        let mut len = 0;
+
+        n == 0
+            || self.ranges.iter().any(|range| {
+                #[cfg(test)]
+                LEN_AT_LEAST_VISITS.with(|visits| visits.set(visits.get() + 1));
+
+                len += range_len(range);
+                len >= n
+            })
+    }
+
+    /** Retrieve number of ranges in class */
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /** Check if character class is empty */
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /** Get the normalized character ranges as a slice. */
+    pub fn ranges(&self) -> &[CharClassRange] {
+        &self.ranges
+    }
+
+    /** Consume the class, returning its normalized character ranges without cloning.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let ccl = charclass!['x' => 'z', 'a' => 'c', '0' => '9'];
+    let ranges: Vec<_> = ccl.into_ranges();
+    assert_eq!(ranges, vec!['0'..='9', 'a'..='c', 'x'..='z']);
+    assert!(ranges.windows(2).all(|pair| pair[0].end() < pair[1].start()));
+    ```
+    */
+    pub fn into_ranges(self) -> Vec<CharClassRange> {
+        self.ranges
+    }
+
+    /** Iterate over owned copies of the character ranges.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let ccl = charclass!['a' => 'c', 'x' => 'z'];
+    let ranges: Vec<_> = ccl.iter_ranges().collect();
+    assert_eq!(ranges, vec!['a'..='c', 'x'..='z']);
+    ```
+    */
+    pub fn iter_ranges(&self) -> impl Iterator<Item = CharClassRange> + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    /** Collect all characters of the class into a set.
+
+    To avoid accidentally materializing huge classes, `None` is returned
+    when the class holds more than `max_len` characters.
+    */
+    pub fn to_set(&self, max_len: u32) -> Option<std::collections::BTreeSet<char>> {
+        if self.len() > max_len {
+            return None;
+        }
+
+        Some(self.ranges.iter().flat_map(|r| r.clone()).collect())
+    }
+
+    /** Get a 256-bit mask of the class's Latin-1 characters.
+
+    This is the inverse of `from_bitset_256()`; characters beyond U+00FF are ignored.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let bits = charclass!['a' => 'z', '€' => '€'].to_bitset_256();
+    assert_eq!(bits, [0, 0x07ff_fffe << 32, 0, 0]);
+    assert_eq!(CharClass::from_bitset_256(&bits), charclass!['a' => 'z']);
+    ```
+    */
+    pub fn to_bitset_256(&self) -> [u64; 4] {
+        let mut bits = [0u64; 4];
+
+        for range in &self.ranges {
+            for ch in range.clone().take_while(|ch| (*ch as u32) < 0x100) {
+                bits[(ch as u32 >> 6) as usize] |= 1 << (ch as u32 & 63);
+            }
+        }
+
+        bits
+    }
+
+    /** Get the class's ranges as body of a regular expression bracket expression.
+
+    Characters special inside brackets, like `]`, `^`, `-` and `\`, are
+    escaped by a backslash, control characters are written as `\x{..}`.
+    The brackets themselves aren't included, so the body can be embedded
+    into a larger regular expression.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let ccl = charclass![']' => ']', '-' => '-', 'a' => 'c', '^' => '^', '\t' => '\t'];
+    let body = ccl.to_regex_bracket_body();
+    assert_eq!(body, "\\x{9}\\-\\]-\\^a-c");
+    assert_eq!(CharClass::from_regex_bracket(&format!("[{}]", body)), Ok(ccl));
+
+    assert_eq!(charclass!['\t', '\\'].to_regex_bracket_body(), "\\x{9}\\\\");
+    ```
+    */
+    pub fn to_regex_bracket_body(&self) -> String {
+        fn escape(s: &mut String, ch: char) {
+            match ch {
+                '\\' | ']' | '[' | '^' | '-' | '&' | '~' => {
+                    s.push('\\');
+                    s.push(ch);
+                }
+                ch if ch.is_control() || (ch.is_whitespace() && ch != ' ') => {
+                    s.push_str(&format!("\\x{{{:x}}}", ch as u32));
+                }
+                ch => s.push(ch),
+            }
+        }
+
+        let mut body = String::new();
+
+        for range in &self.ranges {
+            escape(&mut body, *range.start());
+
+            if range.start() < range.end() {
+                body.push('-');
+                escape(&mut body, *range.end());
+            }
+        }
+
+        body
+    }
+
+    /** Number of ranges of the negated class, without computing it.
+
+    Every gap between the ranges is counted the way `negate()` emits it, so
+    a gap containing the whole surrogate block counts twice.
+    */
+    fn negated_range_count(&self) -> usize {
+        let mut count = 0;
+        let mut from = 0;
+
+        for range in &self.ranges {
+            let start = *range.start() as u32;
+
+            if start > from {
+                count += valid_char_ranges(from, start - 1).count();
+            }
+
+            from = *range.end() as u32 + 1;
+        }
+
+        count + valid_char_ranges(from, std::char::MAX as u32).count()
+    }
+
+    /** Format class as regular expression, choosing the shorter of `[...]` and `[^...]`.
+
+    The negated form `[^...]` is used when the negated class needs fewer
+    ranges, but never with an empty body. Special characters within the brackets are escaped, and control
+    characters are written as `\x{...}`.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    assert_eq!(charclass!['a' => 'z', '-' => '-'].to_regex_string(), "[\\-a-z]");
+    assert_eq!(charclass!['0' => '9'].negate().to_regex_string(), "[^0-9]");
+    assert_eq!(CharClass::new().to_regex_string(), "[^\\x{0}-\u{D7FF}\u{E000}-\u{10FFFF}]");
+    assert_eq!(CharClass::any().to_regex_string(), "[\\x{0}-\u{D7FF}\u{E000}-\u{10FFFF}]");
+
+    // Three ranges either way, as the gap from D7FF to E000 is split in two
+    let ccl = charclass!['\0' => 'a', 'c' => '\u{D7FE}', '\u{E001}' => char::MAX];
+    assert_eq!(ccl.clone().negate().range_count(), 3);
+    assert!(!ccl.to_regex_string().starts_with("[^"));
+    ```
+    */
+    pub fn to_regex_string(&self) -> String {
+        let negated = self.negated_range_count();
+
+        // The negated form of the full class would be the invalid `[^]`
+        if !self.is_empty() && (self.ranges.len() <= negated || negated == 0) {
+            format!("[{}]", self.to_regex_bracket_body())
+        } else {
+            format!("[^{}]", self.clone().negate().to_regex_bracket_body())
+        }
+    }
+
+    /** Compute a hash value that is stable across program runs and versions.
+
+    Uses 64-bit FNV-1a over the start and end code points of every range,
+    each encoded as four little-endian bytes. In contrast to the `Hash`
+    implementation, this value can be persisted, e.g. as a cache key.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    assert_eq!(charclass!['a' => 'z'].stable_hash(), 0xa69c0e4390b010de);
+    ```
+    */
+    pub fn stable_hash(&self) -> u64 {
+        let mut hash: u64 = 0xcbf29ce484222325;
+
+        for range in &self.ranges {
+            for ch in [range.start(), range.end()] {
+                for byte in (*ch as u32).to_le_bytes() {
+                    hash ^= byte as u64;
+                    hash = hash.wrapping_mul(0x100000001b3);
+                }
+            }
+        }
+
+        hash
+    }
+
+    /** Get the smallest single range containing the entire class.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    assert_eq!(charclass!['a' => 'c', 'x' => 'z'].bounding_range(), Some('a'..='z'));
+    assert_eq!(CharClass::new().bounding_range(), None);
+    ```
+    */
+    pub fn bounding_range(&self) -> Option<CharClassRange> {
+        Some(*self.ranges.first()?.start()..=*self.ranges.last()?.end())
+    }
+
+    /** Gather statistics about the class in a single call.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass, ClassStats};
+
+    let stats = charclass!['a' => 'c', 'k' => 'z'].stats();
+    assert_eq!(
+        stats,
+        ClassStats {
+            char_count: 19,
+            range_count: 2,
+            min: Some('a'),
+            max: Some('z'),
+            span: 26,
+            density: 19.0 / 26.0,
+            largest_range: Some('k'..='z'),
+        }
+    );
+
+    let stats = CharClass::new().stats();
+    assert_eq!(stats.char_count, 0);
+    assert_eq!(stats.min, None);
+    assert_eq!(stats.largest_range, None);
+    ```
+    */
+    pub fn stats(&self) -> ClassStats {
+        let bounds = self.bounding_range();
+
+        ClassStats {
+            char_count: self.len(),
+            range_count: self.ranges.len(),
+            min: bounds.as_ref().map(|r| *r.start()),
+            max: bounds.as_ref().map(|r| *r.end()),
+            span: bounds.as_ref().map_or(0, range_len),
+            density: self.density(),
+            largest_range: self
+                .ranges
+                .iter()
+                .rev()
+                .max_by_key(|r| range_len(r))
+                .cloned(),
+        }
+    }
+
+    /** Get the minimum and maximum UTF-8 encoded length of the class's characters.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    assert_eq!(charclass!['a' => 'z'].utf8_len_bounds(), Some((1, 1)));
+    assert_eq!(charclass!['a' => 'z', '€' => '😀'].utf8_len_bounds(), Some((1, 4)));
+    assert_eq!(charclass!['ä' => '€'].utf8_len_bounds(), Some((2, 3)));
+    assert_eq!(CharClass::new().utf8_len_bounds(), None);
+    ```
+    */
+    pub fn utf8_len_bounds(&self) -> Option<(usize, usize)> {
+        let range = self.bounding_range()?;
+        Some((range.start().len_utf8(), range.end().len_utf8()))
+    }
+
+    /** Ratio of the number of characters to the span of the class.
+
+    A value close to 1.0 indicates a dense class, and a value close to 0.0
+    a sparse one. The surrogate gap is not counted as part of the span.
+    An empty class has a density of 0.0.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    assert_eq!(charclass!['a' => 'b', 'i' => 'j'].density(), 0.4);
+    assert_eq!(charclass!['\u{D7FF}', '\u{E000}'].density(), 1.0);
+    assert_eq!(CharClass::new().density(), 0.0);
+    ```
+    */
+    pub fn density(&self) -> f64 {
+        match self.bounding_range() {
+            Some(span) => self.len() as f64 / range_len(&span) as f64,
+            None => 0.0,
+        }
+    }
+
+    /** Split class into sub-classes aligned to windows of `block_size` code points.
+
+    Returns each non-empty window's base code point, together with the
+    class restricted to that window.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let blocks = charclass!['a' => 'z', 'ä' => 'ą'].split_into_blocks(256);
+    assert_eq!(
+        blocks,
+        vec![
+            (0x0, charclass!['a' => 'z', 'ä' => 'ÿ']),
+            (0x100, charclass!['Ā' => 'ą'])
+        ]
+    );
+    ```
+    */
+    pub fn split_into_blocks(&self, block_size: u32) -> Vec<(u32, CharClass)> {
+        assert!(block_size > 0, "block_size must not be 0");
+
+        let mut blocks: Vec<(u32, CharClass)> = Vec::new();
+
+        for range in &self.ranges {
+            let end = *range.end() as u32;
+            let mut start = *range.start() as u32;
+
+            loop {
+                let base = start - start % block_size;
+                let until = end.min(base.saturating_add(block_size - 1));
+
+                for range in valid_char_ranges(start, until) {
+                    match blocks.last_mut() {
+                        Some((last, ccl)) if *last == base => ccl.ranges.push(range),
+                        _ => blocks.push((base, CharClass::from_vec(vec![range]))),
+                    }
+                }
+
+                if until == end {
+                    break;
+                }
+
+                start = until + 1;
+            }
+        }
+
+        blocks
+    }
+
+    /** Compile class into a two-level lookup table covering all code points.
+
+    The code points are split into blocks of `2^block_bits` entries. The first
+    table maps every block to the index of its entries in the second table,
+    where equal blocks are stored only once. Membership of a code point `cp`
+    is then looked up by two array accesses, as usual for Unicode property
+    tables.
+
+    For `block_bits` of 16 or less, the number of distinct blocks always fits
+    into an `u16` index.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let ccl = charclass!['a' => 'z', 'ä' => 'ä', '€' => '€'];
+    let (index, blocks) = ccl.to_two_level_table(6);
+    assert_eq!(index.len(), 0x110000 >> 6);
+    assert_eq!(blocks.len(), 4 << 6);
+
+    let contains = |cp: u32| blocks[(index[(cp >> 6) as usize] as usize) << 6 | (cp & 63) as usize];
+
+    for cp in 0..=0x3000 {
+        let expected = char::from_u32(cp).is_some_and(|ch| ccl.contains(ch));
+        assert_eq!(contains(cp), expected, "{:#x}", cp);
+    }
+    ```
+    */
+    pub fn to_two_level_table(&self, block_bits: u32) -> (Vec<u16>, Vec<bool>) {
+        assert!(block_bits <= 16, "block_bits must not exceed 16");
+
+        let size = 1usize << block_bits;
+        let mut index = Vec::with_capacity(0x110000 >> block_bits);
+        let mut blocks = Vec::new();
+        let mut seen: std::collections::HashMap<Vec<bool>, u16> = std::collections::HashMap::new();
+        let mut block = vec![false; size];
+        let mut first = 0;
+
+        for base in (0..0x110000u32).step_by(size) {
+            let last = base + (size as u32 - 1);
+            block.fill(false);
+
+            while first < self.ranges.len() && (*self.ranges[first].end() as u32) < base {
+                first += 1;
+            }
+
+            for range in self.ranges[first..]
+                .iter()
+                .take_while(|r| (*r.start() as u32) <= last)
+            {
+                let from = (*range.start() as u32).max(base) - base;
+                let to = (*range.end() as u32).min(last) - base;
+                block[from as usize..=to as usize].fill(true);
+            }
+
+            let id = match seen.get(&block) {
+                Some(id) => *id,
+                None => {
+                    let id = seen.len() as u16;
+                    blocks.extend_from_slice(&block);
+                    seen.insert(block.clone(), id);
+                    id
+                }
+            };
+
+            index.push(id);
+        }
+
+        (index, blocks)
+    }
+
+    /** Suggest the cheapest representation for matching the class, e.g. in generated code.
+
+    Empty, full, single character and single range classes are reported as
+    such. Other classes are suggested as `Bitmap` when their span from the
+    smallest to largest character is at most 1024 code points, or at most
+    65536 code points with a density of at least 50%. Any other class is
+    suggested as `Ranges`.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass, Representation};
+
+    assert_eq!(CharClass::new().suggest_representation(), Representation::Empty);
+    assert_eq!(CharClass::any().suggest_representation(), Representation::Full);
+    assert_eq!(charclass!['x'].suggest_representation(), Representation::SingleChar);
+    assert_eq!(charclass!['a' => 'z'].suggest_representation(), Representation::SingleRange);
+
+    let dense = charclass!['0' => '9', 'A' => 'Z', '_' => '_', 'a' => 'z'];
+    assert_eq!(dense.suggest_representation(), Representation::Bitmap);
+
+    let sparse = charclass!['a', '€', '😀'];
+    assert_eq!(sparse.suggest_representation(), Representation::Ranges);
+    ```
+    */
+    pub fn suggest_representation(&self) -> Representation {
+        const BITMAP_MAX_SPAN: u32 = 1024;
+        const DENSE_BITMAP_MAX_SPAN: u32 = 0x10000;
+
+        if self.is_empty() {
+            return Representation::Empty;
+        } else if self.is_full_unicode() {
+            return Representation::Full;
+        } else if self.is_contiguous() {
+            return if self.len() == 1 {
+                Representation::SingleChar
+            } else {
+                Representation::SingleRange
+            };
+        }
+
+        let span = self.bounding_range().as_ref().map_or(0, range_len);
+
+        if span <= BITMAP_MAX_SPAN || (span <= DENSE_BITMAP_MAX_SPAN && self.density() >= 0.5) {
+            Representation::Bitmap
+        } else {
+            Representation::Ranges
+        }
+    }
+
+    /** Check if the class consists of exactly one range.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    assert!(charclass!['a' => 'z'].is_contiguous());
+    assert!(!charclass!['a' => 'c', 'x' => 'z'].is_contiguous());
+    assert!(!CharClass::new().is_contiguous());
+    ```
+    */
+    pub fn is_contiguous(&self) -> bool {
+        self.ranges.len() == 1
+    }
+
+    /** Compute the Jaccard similarity between two character classes.
+
+    This is the number of characters in the intersection, divided by the
+    number of characters in the union of both classes. Disjoint classes
+    have a similarity of 0.0; two empty classes are defined to have a
+    similarity of 1.0.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let ccl = charclass!['a' => 'f'];
+    assert_eq!(ccl.jaccard(&ccl), 1.0);
+    assert_eq!(ccl.jaccard(&charclass!['x' => 'z']), 0.0);
+    assert_eq!(ccl.jaccard(&charclass!['d' => 'i']), 0.333_333_333_333_333_3);
+    assert_eq!(CharClass::new().jaccard(&CharClass::new()), 1.0);
+    ```
+    */
+    pub fn jaccard(&self, other: &CharClass) -> f64 {
+        let mut intersection = 0;
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let a = &self.ranges[i];
+            let b = &other.ranges[j];
+
+            intersection += range_len(&(*a.start().max(b.start())..=*a.end().min(b.end())));
+
+            if a.end() < b.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        let union = self.len() + other.len() - intersection;
+
+        if union == 0 {
+            1.0
+        } else {
+            intersection as f64 / union as f64
+        }
+    }
+
+    /** Dump the ranges as a string of decimal code point pairs, like `[[97,122]]`.
+
+    This form is stable and machine-readable, and can be parsed back using
+    `from_range_pairs_string()`.
+    */
+    pub fn to_range_pairs_string(&self) -> String {
+        let pairs: Vec<String> = self
+            .ranges
+            .iter()
+            .map(|r| format!("[{},{}]", *r.start() as u32, *r.end() as u32))
+            .collect();
+
+        format!("[{}]", pairs.join(","))
+    }
+
+    /** Iterate over all characters of the class, together with their 0-based ordinal.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let ccl = charclass!['a', 'b', 'c', 'x'];
+    let chars: Vec<_> = ccl.iter_indexed().collect();
+    assert_eq!(chars, vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'x')]);
+    ```
+    */
+    pub fn iter_indexed(&self) -> impl Iterator<Item = (u32, char)> + '_ {
+        (0..).zip(self.ranges.iter().flat_map(|r| r.clone()))
+    }
+
+    /** Dump the character ranges */
+    pub fn dump(&self) {
+        println!("{:p} ranges={}", self, self.ranges.len());
+
+        for i in 0..self.ranges.len() {
+            println!("  {:?}", self.ranges[i]);
+        }
+    }
+
+    /** Normalize character-class by removing intersections and coherent ranges.
+
+    Normalization is idempotent, and never merges ranges across the surrogate gap.
+    Instead, any range crossing the surrogate gap is split into two ranges.
+
+    Example:
+    ```
+    use charclass::CharClass;
+
+    let mut ccl = CharClass::from_ranges_unchecked(vec![
+        '\u{E000}'..='\u{E0FF}',
+        'a'..='z',
+        '\u{D700}'..='\u{D7FF}',
+        'k'..='{',
+    ]);
+
+    ccl.normalize();
+    assert_eq!(ccl.ranges(), &['a'..='{', '\u{D700}'..='\u{D7FF}', '\u{E000}'..='\u{E0FF}']);
+
+    let once = ccl.clone();
+    ccl.normalize();
+    assert_eq!(ccl, once);
+
+    let mut ccl = CharClass::from_ranges_unchecked(vec!['a'..='\u{FFFF}']);
+    ccl.normalize();
+    assert_eq!(ccl.ranges(), &['a'..='\u{D7FF}', '\u{E000}'..='\u{FFFF}']);
+
+    let mut ccl = CharClass::from_ranges_unchecked(vec!['\u{D7FE}'..='\u{D7FF}', '\u{D7FE}'..='\u{E0FF}']);
+    ccl.normalize();
+    assert_eq!(ccl.ranges(), &['\u{D7FE}'..='\u{D7FF}', '\u{E000}'..='\u{E0FF}']);
+    ```
+    */
+    pub fn normalize(&mut self) {
+        #[cfg(test)]
+        NORMALIZE_CALLS.with(|calls| calls.set(calls.get() + 1));
+
+        self.invalidate();
+
+        let mut ranges: Vec<CharClassRange> = std::mem::take(&mut self.ranges);
+        ranges.sort_by(|a, b| a.start().cmp(b.start()));
+
+        for range in ranges {
+            if range.is_empty() {
+                continue;
+            }
+
+            let mut range = range;
+
+            if let Some(last) = self.ranges.last() {
+                // Merge intersecting or coherent ranges
+                if range.start() <= last.end() || is_adjacent(*last.end(), *range.start()) {
+                    if range.end() <= last.end() {
+                        continue;
+                    }
+
+                    range = *last.start()..=*range.end();
+                    self.ranges.pop();
+                }
+            }
+
+            // Split ranges crossing the surrogate gap, also after merging
+            self.ranges.extend(valid_char_ranges(
+                *range.start() as u32,
+                *range.end() as u32,
+            ));
+        }
+
+        debug_assert!(self.is_normalized());
+    }
+
+    /** Check that ranges are sorted, non-overlapping, non-adjacent and not crossing the surrogate gap. */
+    fn is_normalized(&self) -> bool {
+        let valid = |r: &CharClassRange| {
+            r.start() <= r.end() && !(*r.start() <= '\u{D7FF}' && *r.end() >= '\u{E000}')
+        };
+
+        self.ranges.iter().all(valid)
+            && self.ranges.windows(2).all(|pair| {
+                pair[0].end() < pair[1].start() && !is_adjacent(*pair[0].end(), *pair[1].start())
+            })
+    }
+
+    /** Bring class into its canonical form, which is stable across crate versions.
+
+    The canonical form consists of the minimal number of sorted ranges, where
+    no range crosses the surrogate gap. So any full range is split into the
+    two ranges of `CharClass::any()`. Classes in canonical form are equal
+    if, and only if, they hold the same characters.
+
+    Example:
+    ```
+    use charclass::CharClass;
+
+    let mut legacy = CharClass::from_ranges_unchecked(vec!['\0'..=char::MAX]);
+    assert_ne!(legacy, CharClass::any());
+
+    legacy.canonicalize();
+    assert_eq!(legacy, CharClass::any());
+    ```
+    */
+    pub fn canonicalize(&mut self) {
+        self.normalize();
+    }
+
+    /** Bring class into its minimal representation, e.g. before serialization.
+
+    This fully normalizes the class, so it uses the minimal number of ranges,
+    and releases any excess memory held by the ranges. It is idempotent.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let mut ccl = CharClass::from_ranges_unchecked(vec!['d'..='f', 'a'..='c', 'x'..='z', 'e'..='h']);
+    ccl.minimize();
+    assert_eq!(ccl.ranges(), &['a'..='h', 'x'..='z']);
+
+    let once = ccl.clone();
+    ccl.minimize();
+    assert_eq!(ccl, once);
+
+    let v = ccl.into_ranges();
+    assert_eq!(v.capacity(), v.len());
+    ```
+    */
+    pub fn minimize(&mut self) {
+        self.normalize();
+        self.ranges.shrink_to_fit();
+    }
+
+    /** Negate entire character class
+
+    The complement is built in a single pass, by emitting the gaps between
+    the sorted ranges plus the leading and trailing gap.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let ccl = charclass!['b' => 'c', 'x' => 'z'];
+    assert_eq!(
+        ccl.clone().negate(),
+        charclass!['\0' => 'a', 'd' => 'w', '{' => '\u{D7FF}', '\u{E000}' => std::char::MAX]
+    );
+    assert_eq!(ccl.clone().negate().negate(), ccl);
+    assert_eq!(CharClass::new().negate(), CharClass::any());
+    assert!(CharClass::any().negate().is_empty());
+    ```
+    */
+    pub fn negate(self) -> CharClass {
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 2);
+        let mut from = Some('\0');
+
+        for range in &self.ranges {
+            if let (Some(start), Some(end)) = (from, prev_char(*range.start())) {
+                if start <= end {
+                    ranges.extend(valid_char_ranges(start as u32, end as u32));
+                }
+            }
+
+            from = next_char(*range.end());
+        }
+
+        if let Some(start) = from {
+            ranges.extend(valid_char_ranges(start as u32, std::char::MAX as u32));
+        }
+
+        let ccl = CharClass::from_vec(ranges);
+        debug_assert!(ccl.is_normalized());
+        ccl
+    }
+
+    /** Merge ranges separated by gaps of at most `max_gap` characters.
+
+    This is lossy: the characters within the merged gaps become part of the
+    resulting class, in exchange for fewer ranges. With a `max_gap` of 0,
+    this is equal to normalization. Gaps are counted in valid characters, so
+    the surrogates don't contribute to them.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    assert_eq!(charclass!['a', 'c'].coalesce(1), charclass!['a' => 'c']);
+    assert_eq!(charclass!['a', 'd'].coalesce(1), charclass!['a', 'd']);
+    assert_eq!(
+        charclass!['\u{D7FF}', '\u{E001}'].coalesce(1),
+        charclass!['\u{D7FF}' => '\u{E001}']
+    );
+    ```
+    */
+    pub fn coalesce(&self, max_gap: u32) -> CharClass {
+        let mut ranges: Vec<CharClassRange> = Vec::with_capacity(self.ranges.len());
+
+        for range in &self.ranges {
+            if let Some(last) = ranges.last_mut() {
+                // Count the gap in valid characters, so surrogates don't widen it
+                let gap = match (next_char(*last.end()), prev_char(*range.start())) {
+                    (Some(start), Some(end)) => range_len(&(start..=end)),
+                    _ => 0,
+                };
+
+                if gap <= max_gap {
+                    if range.end() > last.end() {
+                        *last = *last.start()..=*range.end();
+                    }
+
+                    continue;
+                }
+            }
+
+            ranges.push(range.clone());
+        }
+
+        let mut ccl = CharClass::from_vec(ranges);
+        ccl.normalize();
+        ccl
+    }
+
+    /** Negate character class within the ASCII range.
+
+    The result only contains characters from `'\0'..='\u{7F}'`.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let ccl = charclass!['a' => 'z'].complement_ascii();
+    assert!(ccl.test(&('A'..='A')));
+    assert!(ccl.test(&('0'..='0')));
+    assert!(!ccl.test(&('m'..='m')));
+    assert!(!ccl.test(&('é'..='é')));
+    assert_eq!(ccl.len(), 128 - 26);
+    ```
+    */
+    pub fn complement_ascii(&self) -> CharClass {
+        let mut ranges = Vec::new();
+        let mut start = 0u32;
+
+        for range in &self.ranges {
+            if start > 0x7f {
+                break;
+            }
+
+            if (*range.start() as u32) > start {
+                let end = (*range.start() as u32 - 1).min(0x7f);
+                ranges.push(char::from(start as u8)..=char::from(end as u8));
+            }
+
+            start = *range.end() as u32 + 1;
+        }
+
+        if start <= 0x7f {
+            ranges.push(char::from(start as u8)..='\u{7F}');
+        }
+
+        CharClass::from_vec(ranges)
+    }
+
+    /** Expand every range outwards to start and end on `block`-aligned boundaries.
+
+    This is lossy: the characters added by the expansion become part of the
+    resulting class, in exchange for aligned ranges. Surrogates within the
+    expanded ranges are left out.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    assert_eq!(charclass!['b' => 'e'].align_to(16), charclass!['\x60' => '\x6f']);
+    assert_eq!(charclass!['b', 'q'].align_to(16), charclass!['\x60' => '\x7f']);
+    ```
+    */
+    pub fn align_to(&self, block: u32) -> CharClass {
+        assert!(block > 0, "block must not be 0");
+
+        let mut ranges = Vec::with_capacity(self.ranges.len());
+
+        for range in &self.ranges {
+            let start = *range.start() as u32;
+            let end = *range.end() as u32;
+
+            ranges.extend(valid_char_ranges(
+                start - start % block,
+                (end - end % block).saturating_add(block - 1),
+            ));
+        }
+
+        let mut ccl = CharClass::from_vec(ranges);
+        ccl.normalize();
+        ccl
+    }
+
+    /** Shift every character of the class by `by` code points.
+
+    Negative values shift downwards. The surrogate gap is skipped, so that
+    `'\u{D7FF}'` shifted by 1 becomes `'\u{E000}'`. Characters shifted beyond
+    `'\0'` or `char::MAX` are clamped to these bounds.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    assert_eq!(charclass!['a' => 'c'].shift(3), charclass!['d' => 'f']);
+    assert_eq!(charclass!['a' => 'c'].shift(-97), charclass!['\0' => '\x02']);
+    assert_eq!(charclass!['a' => 'c'].shift(-98), charclass!['\0' => '\x01']);
+    assert_eq!(charclass!['\u{D7FE}'].shift(2), charclass!['\u{E000}']);
+    assert_eq!(charclass![char::MAX].shift(1), charclass![char::MAX]);
+    ```
+    */
+    pub fn shift(&self, by: i32) -> CharClass {
+        // Maps between characters and a contiguous index without the surrogate gap
+        fn index(ch: char) -> i64 {
+            let ch = ch as i64;
+            if ch >= 0xe000 {
+                ch - 0x800
+            } else {
+                ch
+            }
+        }
+
+        fn code_point(index: i64) -> u32 {
+            let index = index.clamp(0, std::char::MAX as i64 - 0x800) as u32;
+            if index >= 0xd800 {
+                index + 0x800
+            } else {
+                index
+            }
+        }
+
+        let mut ranges = Vec::with_capacity(self.ranges.len());
+
+        for range in &self.ranges {
+            let start = code_point(index(*range.start()) + by as i64);
+            let end = code_point(index(*range.end()) + by as i64);
+            ranges.extend(valid_char_ranges(start, end));
+        }
+
+        let mut ccl = CharClass::from_vec(ranges);
+        ccl.normalize();
+        ccl
+    }
+
+    /** Add range to character class. */
+    pub fn add(&mut self, range: CharClassRange) -> u32 {
+        let len = self.len();
+        self.ranges.push(range);
+        self.normalize();
+        // An unnormalized class may have counted overlapping characters twice
+        self.len().saturating_sub(len)
+    }
+
+    /** Add range to character class, reporting how it overlapped with the class.
+
+    Example:
+    ```
+    use charclass::{charclass, AddOutcome};
+
+    let mut ccl = charclass!['a' => 'f'];
+    assert_eq!(ccl.add_reporting('x'..='z'), AddOutcome::New);
+    assert_eq!(ccl.add_reporting('d'..='h'), AddOutcome::Overlapping(3));
+    assert_eq!(ccl.add_reporting('b'..='c'), AddOutcome::Redundant);
+    assert_eq!(ccl, charclass!['a' => 'h', 'x' => 'z']);
+    ```
+    */
+    pub fn add_reporting(&mut self, range: CharClassRange) -> AddOutcome {
+        let len = range_len(&range);
+        let added = self.add(range);
+
+        if added == len && len > 0 {
+            AddOutcome::New
+        } else if added > 0 {
+            AddOutcome::Overlapping(len - added)
+        } else {
+            AddOutcome::Redundant
+        }
+    }
+
+    /** Add range to character class, returning `true` if the class gained any characters.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let mut ccl = charclass!['a' => 'f'];
+    assert!(!ccl.add_changed('b'..='d'));
+    assert!(ccl.add_changed('e'..='h'));
+    assert_eq!(ccl, charclass!['a' => 'h']);
+    ```
+    */
+    pub fn add_changed(&mut self, range: CharClassRange) -> bool {
+        self.add(range) > 0
+    }
+
+    /** Add any kind of range to character class.
+
+    Exclusive and unbounded ranges are supported as well, e.g. `'a'..'z'` or `..='z'`.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let mut ccl = CharClass::new();
+    ccl.add_bounds('a'..'d');
+    assert_eq!(ccl, charclass!['a' => 'c']);
+
+    ccl.add_bounds('x'..);
+    assert_eq!(ccl, charclass!['a' => 'c', 'x' => char::MAX]);
+    ```
+    */
+    pub fn add_bounds<R: std::ops::RangeBounds<char>>(&mut self, range: R) -> u32 {
+        match bounds_to_range(range) {
+            Some(range) => self.add(range),
+            None => 0,
+        }
+    }
+
+    /** Remove the given characters from character class.
+
+    All characters are removed in a single pass over the ranges.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let mut ccl = charclass!['a' => 'z'];
+    ccl.subtract_chars(['i', 'o']);
+    assert_eq!(ccl, charclass!['a' => 'h', 'j' => 'n', 'p' => 'z']);
+    ```
+    */
+    pub fn subtract_chars<I: IntoIterator<Item = char>>(&mut self, chars: I) {
+        let mut chars: Vec<char> = chars.into_iter().collect();
+        chars.sort_unstable();
+
+        *self -= CharClass::from_sorted_chars(chars);
+    }
+
+    /** Insert a single character, returning `true` if it wasn't already present.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let mut ccl = charclass!['a' => 'c'];
+    assert!(!ccl.insert('b'));
+    assert!(ccl.insert('x'));
+    assert_eq!(ccl, charclass!['a' => 'c', 'x' => 'x']);
+    ```
+    */
+    pub fn insert(&mut self, ch: char) -> bool {
+        self.add(ch..=ch) > 0
+    }
+
+    /** Remove range from character class.
+
+    Returns the number of characters removed. Splitting a range shifts all
+    following ranges, so this is O(n) in the number of ranges; to remove
+    many characters at once, prefer `subtract_chars()` or `-`, which do a
+    single pass.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let mut ccl = charclass!['a' => 'z'];
+    assert_eq!(ccl.remove('x'..='~'), 3);
+    assert_eq!(ccl.remove('e'..='f'), 2);
+    assert_eq!(ccl, charclass!['a' => 'd', 'g' => 'w']);
+    ```
+    */
+    pub fn remove(&mut self, range: CharClassRange) -> u32 {
+        if range.is_empty() {
+            return 0;
+        }
+
+        let first = self.ranges.partition_point(|r| r.end() < range.start());
+        let last = self.ranges.partition_point(|r| r.start() <= range.end());
+
+        if first >= last {
+            return 0;
+        }
+
+        let len = self.len();
+        self.invalidate();
+
+        let head = if self.ranges[first].start() < range.start() {
+            Some(*self.ranges[first].start()..=prev_char(*range.start()).unwrap())
+        } else {
+            None
+        };
+
+        let tail = if self.ranges[last - 1].end() > range.end() {
+            Some(next_char(*range.end()).unwrap()..=*self.ranges[last - 1].end())
+        } else {
+            None
+        };
+
+        self.ranges
+            .splice(first..last, head.into_iter().chain(tail));
+        debug_assert!(self.is_normalized());
+        len - self.len()
+    }
+
+    /** Remove a single character, returning `true` if it was present.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let mut ccl = charclass!['a' => 'c'];
+    assert!(ccl.remove_char('b'));
+    assert!(!ccl.remove_char('b'));
+    assert_eq!(ccl, charclass!['a', 'c']);
+    ```
+    */
+    pub fn remove_char(&mut self, ch: char) -> bool {
+        self.remove(ch..=ch) > 0
+    }
+
+    /** Remove range from character class, returning `true` if the class lost any characters.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let mut ccl = charclass!['a' => 'f'];
+    assert!(!ccl.remove_changed('x'..='z'));
+    assert!(ccl.remove_changed('e'..='z'));
+    assert_eq!(ccl, charclass!['a' => 'd']);
+    ```
+    */
+    pub fn remove_changed(&mut self, range: CharClassRange) -> bool {
+        self.remove(range) > 0
+    }
+
+    /** Add ranges given in ascending order of their starts.
+
+    Overlapping and adjacent ranges are merged on the fly, so mostly sorted
+    input avoids the cost of a full normalization. Only when a range arrives
+    out of order, the class gets fully normalized at the end.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let mut ccl = charclass!['a' => 'c'];
+    ccl.extend_sorted(['d'..='f', 'e'..='h', 'x'..='z']);
+    assert_eq!(ccl, charclass!['a' => 'h', 'x' => 'z']);
+
+    ccl.extend_sorted(['m'..='n', 'b'..='i']);
+    assert_eq!(ccl, charclass!['a' => 'i', 'm' => 'n', 'x' => 'z']);
+    ```
+    */
+    pub fn extend_sorted<I: IntoIterator<Item = CharClassRange>>(&mut self, iter: I) {
+        let mut sorted = true;
+        self.invalidate();
+
+        let ranges = iter
+            .into_iter()
+            .flat_map(|r| valid_char_ranges(*r.start() as u32, *r.end() as u32));
+
+        for range in ranges {
+            if let Some(last) = self.ranges.last_mut() {
+                if range.start() < last.start() {
+                    sorted = false;
+                } else if range.start() <= last.end() || is_adjacent(*last.end(), *range.start()) {
+                    if range.end() > last.end() {
+                        *last = *last.start()..=*range.end();
+                    }
+
+                    continue;
+                }
+            }
+
+            self.ranges.push(range);
+        }
+
+        if !sorted {
+            self.normalize();
+        }
+
+        debug_assert!(self.is_normalized());
+    }
+
+    /** Add range of code points to character class.
+
+    Any surrogate code points within the range are left out, so a range
+    spanning the surrogate gap is split into its two valid sub-ranges. A
+    range consisting of surrogates only is rejected, as it holds no valid
+    character. Returns the number of characters added.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass, CharClassError};
+
+    let mut ccl = CharClass::new();
+    assert_eq!(ccl.add_u32_range(0xd700, 0xe0ff), Ok(0x200));
+    assert_eq!(ccl, charclass!['\u{D700}' => '\u{D7FF}', '\u{E000}' => '\u{E0FF}']);
+
+    assert_eq!(ccl.add_u32_range(0x62, 0x61), Err(CharClassError::ReversedRange(0x62, 0x61)));
+    assert_eq!(ccl.add_u32_range(0x61, 0x110000), Err(CharClassError::InvalidCodePoint(0x110000)));
+    assert_eq!(ccl.add_u32_range(0xd800, 0xdbff), Err(CharClassError::SurrogateRange(0xd800, 0xdbff)));
+    ```
+    */
+    pub fn add_u32_range(&mut self, start: u32, end: u32) -> Result<u32, CharClassError> {
+        if start > end {
+            return Err(CharClassError::ReversedRange(start, end));
+        } else if end > std::char::MAX as u32 {
+            return Err(CharClassError::InvalidCodePoint(end));
+        } else if start >= 0xd800 && end <= 0xdfff {
+            return Err(CharClassError::SurrogateRange(start, end));
+        }
+
+        Ok(valid_char_ranges(start, end)
+            .map(|range| self.add(range))
+            .sum())
+    }
+
+    /** Add range of code points to character class, rejecting any surrogates.
+
+    In contrast to `add_u32_range()`, a range including surrogate code points
+    isn't split, but results in an error. Returns the number of characters added.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass, CharClassError};
+
+    let mut ccl = CharClass::new();
+    assert_eq!(ccl.add_u32_range_strict(0x61, 0x7a), Ok(26));
+    assert_eq!(
+        ccl.add_u32_range_strict(0xd700, 0xe0ff),
+        Err(CharClassError::SurrogateRange(0xd700, 0xe0ff))
+    );
+    assert_eq!(
+        ccl.add_u32_range_strict(0xdc00, 0xdc00),
+        Err(CharClassError::SurrogateRange(0xdc00, 0xdc00))
+    );
+    assert_eq!(ccl, charclass!['a' => 'z']);
+
+    assert_eq!(ccl.add_u32_range(0xd700, 0xe0ff), Ok(0x200));
+    ```
+    */
+    pub fn add_u32_range_strict(&mut self, start: u32, end: u32) -> Result<u32, CharClassError> {
+        if start <= end && start <= 0xdfff && end >= 0xd800 {
+            return Err(CharClassError::SurrogateRange(start, end));
+        }
+
+        self.add_u32_range(start, end)
+    }
+
+    /** Clears entire range to be empty. */
+    pub fn clear(&mut self) {
+        self.invalidate();
+        self.ranges.clear();
+    }
+
+    /** Take the class out, leaving an empty class in its place.
+
+    This is `std::mem::take()` as a method, e.g. to call consuming methods
+    like `negate()` on a class only borrowed mutably.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let mut ccl = charclass!['a' => 'z'];
+    assert_eq!(ccl.take(), charclass!['a' => 'z']);
+    assert!(ccl.is_empty());
+
+    fn invert(ccl: &mut CharClass) {
+        *ccl = ccl.take().negate();
+    }
+
+    let mut ccl = charclass!['a' => 'z'];
+    invert(&mut ccl);
+    assert!(!ccl.contains('a'));
+    assert_eq!(ccl, CharClass::from_regex_bracket("[^a-z]").unwrap());
+    ```
+    */
+    pub fn take(&mut self) -> CharClass {
+        std::mem::take(self)
+    }
+
+    /** Test if the entire range is part of the character class.
+
+    An empty (reversed) range is never contained.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let ccl = charclass!['a' => 'c', 'k' => 'v'];
+    assert!(ccl.test(&('l'..='o')));
+    assert!(!ccl.test(&('a'..='d')));
+    assert!(!ccl.test(&('c'..='k')));
+
+    assert!(CharClass::any().test(&('\0'..=char::MAX)));
+    ```
+    */
+    pub fn test(&self, range: &CharClassRange) -> bool {
+        CharClassRef::new(&self.ranges).test(range)
+    }
+
+    /** Test if a character is part of the character class.
+
+    Anything convertible into a `char` can be tested, including bytes
+    taken as Latin-1 characters.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let ccl = charclass!['a' => 'c', 'x' => 'z'];
+    assert!(ccl.contains('b'));
+    assert!(ccl.contains(b'b'));
+    assert!(!ccl.contains('d'));
+    assert!(!ccl.contains(b'd'));
+    ```
+    */
+    pub fn contains(&self, ch: impl Into<char>) -> bool {
+        let ch = ch.into();
+
+        #[cfg(feature = "cache")]
+        if ch.is_ascii() {
+            let bits = self.cache.get_or_init(|| {
+                let bits = self.to_bitset_256();
+                [bits[0], bits[1]]
+            });
+
+            return bits[(ch as usize) >> 6] & (1 << (ch as u32 & 63)) != 0;
+        }
+
+        CharClassRef::new(&self.ranges).contains(ch)
+    }
+
+    /** Test if a byte, taken as Latin-1 character, is part of the character class.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let ccl = charclass!['a' => 'z', 'ä' => 'ä'];
+    assert!(ccl.contains_byte(b'q'));
+    assert!(ccl.contains_byte(0xe4));
+    assert!(!ccl.contains_byte(b'A'));
+    assert!(!ccl.contains_byte(0xc4));
+    ```
+    */
+    pub fn contains_byte(&self, b: u8) -> bool {
+        self.contains(char::from(b))
+    }
+
+    /** Test if any kind of range is entirely part of the character class.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let ccl = charclass!['\0' => 'z'];
+    assert!(ccl.contains_bounds('a'..'{'));
+    assert!(ccl.contains_bounds(..='z'));
+    assert!(!ccl.contains_bounds('a'..));
+    assert!(!ccl.contains_bounds('a'..'a'));
+    ```
+    */
+    pub fn contains_bounds<R: std::ops::RangeBounds<char>>(&self, range: R) -> bool {
+        match bounds_to_range(range) {
+            Some(range) => self.test(&range),
+            None => false,
+        }
+    }
+
+    /** Count the ranges of the class which intersect with the given range.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let ccl = charclass!['a', 'c', 'e', 'g', 'i'];
+    assert_eq!(ccl.count_ranges_in(&('b'..='g')), 3);
+    assert_eq!(ccl.count_ranges_in(&('j'..='z')), 0);
+    assert_eq!(ccl.count_ranges_in(&('z'..='a')), 0);
+    ```
+    */
+    pub fn count_ranges_in(&self, range: &CharClassRange) -> usize {
+        if range.is_empty() {
+            return 0;
+        }
+
+        let first = self.ranges.partition_point(|r| r.end() < range.start());
+        let last = self.ranges.partition_point(|r| r.start() <= range.end());
+
+        last.saturating_sub(first)
+    }
+
+    /** Get the part of the class within the given range.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    assert_eq!(charclass!['a' => 'z'].clip(&('m'..='p')), charclass!['m' => 'p']);
+    assert_eq!(
+        charclass!['a' => 'c', 'x' => 'z'].clip(&('b'..='y')),
+        charclass!['b' => 'c', 'x' => 'y']
+    );
+    ```
+    */
+    pub fn clip(&self, range: &CharClassRange) -> CharClass {
+        let first = self.ranges.partition_point(|r| r.end() < range.start());
+        let last = self.ranges.partition_point(|r| r.start() <= range.end());
+
+        if range.is_empty() || first >= last {
+            return CharClass::new();
+        }
+
+        let mut ranges = self.ranges[first..last].to_vec();
+
+        ranges[0] = *ranges[0].start().max(range.start())..=*ranges[0].end();
+
+        let tail = ranges.len() - 1;
+        ranges[tail] = *ranges[tail].start()..=*ranges[tail].end().min(range.end());
+
+        CharClass::from_vec(ranges)
+    }
+
+    /** Get the characters of `range` which are not part of the class.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let ccl = charclass!['a' => 'm'];
+    assert_eq!(ccl.uncovered(&('h'..='s')), charclass!['n' => 's']);
+    assert!(ccl.uncovered(&('b'..='c')).is_empty());
+    assert_eq!(ccl.uncovered(&('0'..='9')), charclass!['0' => '9']);
+    ```
+    */
+    pub fn uncovered(&self, range: &CharClassRange) -> CharClass {
+        CharClass::range(*range.start(), *range.end()) - self.clip(range)
+    }
+
+    /** Restrict the class in place to the characters within `window`.
+
+    This is the in-place counterpart of `clip()`, so it doesn't allocate.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let mut ccl = charclass!['a' => 'z'];
+    ccl.clamp('d'..='f');
+    assert_eq!(ccl, charclass!['d' => 'f']);
+
+    let mut ccl = charclass!['a' => 'c', 'k' => 'm', 'x' => 'z'];
+    ccl.clamp('b'..='y');
+    assert_eq!(ccl, charclass!['b' => 'c', 'k' => 'm', 'x' => 'y']);
+
+    ccl.clamp('d'..='j');
+    assert!(ccl.is_empty());
+    ```
+    */
+    pub fn clamp(&mut self, window: CharClassRange) {
+        self.invalidate();
+
+        let first = self.ranges.partition_point(|r| r.end() < window.start());
+        let last = self.ranges.partition_point(|r| r.start() <= window.end());
+
+        if window.is_empty() || first >= last {
+            self.ranges.clear();
+            return;
+        }
+
+        self.ranges.truncate(last);
+        self.ranges.drain(..first);
+
+        let head = &mut self.ranges[0];
+        *head = *head.start().max(window.start())..=*head.end();
+
+        let tail = self.ranges.last_mut().unwrap();
+        *tail = *tail.start()..=*tail.end().min(window.end());
+
+        debug_assert!(self.is_normalized());
+    }
+
+    /** Iterate over the ranges of the class, clipped to the given window.
+
+    This is the lazy, borrowing counterpart of `clip()`, e.g. for processing
+    a large class page by page.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let ccl = charclass!['a' => 'f', 'k' => 'p', 'x' => 'z'];
+    assert_eq!(
+        ccl.ranges_in(&('d'..='m')).collect::<Vec<_>>(),
+        vec!['d'..='f', 'k'..='m']
+    );
+    assert_eq!(ccl.ranges_in(&('g'..='j')).count(), 0);
+    ```
+    */
+    pub fn ranges_in<'a>(
+        &'a self,
+        window: &CharClassRange,
+    ) -> impl Iterator<Item = CharClassRange> + 'a {
+        let (start, end) = (*window.start(), *window.end());
+        let first = self.ranges.partition_point(|r| *r.end() < start);
+
+        self.ranges[first..]
+            .iter()
+            .take_while(move |r| *r.start() <= end)
+            .map(move |r| *r.start().max(&start)..=*r.end().min(&end))
+            .filter(|r| !r.is_empty())
+    }
+
+    /** Get the part of the class within the printable ASCII range `' '..='~'`.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let ccl = charclass!['\0' => 'z', 'ä' => 'ö'];
+    assert_eq!(ccl.ascii_printable(), charclass![' ' => 'z']);
+    ```
+    */
+    pub fn ascii_printable(&self) -> CharClass {
+        self.clip(&(' '..='~'))
+    }
+
+    /** Check if both classes hold the same characters, regardless of their internal state.
+
+    In contrast to `==`, this also works on classes with ranges that aren't
+    normalized, e.g. from `from_ranges_unchecked()`.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let ccl = CharClass::from_ranges_unchecked(vec!['x'..='z', 'a'..='c', 'd'..='f']);
+    assert_ne!(ccl, charclass!['a' => 'f', 'x' => 'z']);
+    assert!(ccl.set_eq(&charclass!['a' => 'f', 'x' => 'z']));
+    assert!(!ccl.set_eq(&charclass!['a' => 'f']));
+    ```
+    */
+    pub fn set_eq(&self, other: &CharClass) -> bool {
+        let normalized = |ccl: &CharClass| {
+            let mut ccl = ccl.clone();
+            ccl.normalize();
+            ccl
+        };
+
+        normalized(self) == normalized(other)
+    }
+
+    /** Check if `self - other` is empty, which means `self` is a subset of `other`.
+
+    This doesn't compute the difference, but stops at the first character
+    of `self` not covered by `other`.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let ccl = charclass!['a' => 'z'];
+    assert!(charclass!['c' => 'f', 'x' => 'x'].difference_is_empty(&ccl));
+    assert!(!charclass!['c' => 'f', 'X' => 'X'].difference_is_empty(&ccl));
+    assert!(CharClass::new().difference_is_empty(&ccl));
+    ```
+    */
+    pub fn difference_is_empty(&self, other: &CharClass) -> bool {
+        let mut j = 0;
+
+        for range in &self.ranges {
+            let mut start = *range.start();
+
+            loop {
+                // Skip any ranges of other that end before start
+                while j < other.ranges.len() && *other.ranges[j].end() < start {
+                    j += 1;
+                }
+
+                match other.ranges.get(j) {
+                    Some(other) if *other.start() <= start => {
+                        if other.end() >= range.end() {
+                            break;
+                        }
+
+                        start = next_char(*other.end()).unwrap();
+                    }
+                    _ => return false,
+                }
+            }
+        }
+
+        true
+    }
+
+    /** Iterate over the maximal ranges both classes have in common.
+
+    This is the intersection `self & other` as a lazy iterator of ranges.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let a = charclass!['a' => 'm'];
+    let b = charclass!['h' => 'z'];
+    assert_eq!(a.overlaps_with(&b).collect::<Vec<_>>(), vec!['h'..='m']);
+
+    let c = charclass!['0' => '9', 'b' => 'c', 'k' => 'x'];
+    assert_eq!(a.overlaps_with(&c).collect::<Vec<_>>(), vec!['b'..='c', 'k'..='m']);
+    assert_eq!(a.overlaps_with(&charclass!['0' => '9']).count(), 0);
+    ```
+    */
+    pub fn overlaps_with<'a>(
+        &'a self,
+        other: &'a CharClass,
+    ) -> impl Iterator<Item = CharClassRange> + 'a {
+        overlapping_ranges(&self.ranges, &other.ranges)
+    }
+
+    /** Compare two classes by the subset relation.
+
+    Returns `Less` when `self` is a strict subset of `other`, `Greater` when
+    it is a strict superset, `Equal` when both are equal, and `None` when
+    neither contains the other. Unlike `partial_cmp()`, this is the order
+    of the subset lattice.
+
+    Example:
+    ```
+    use charclass::charclass;
+    use std::cmp::Ordering;
+
+    let ccl = charclass!['a' => 'z'];
+    assert_eq!(charclass!['c' => 'f'].subset_cmp(&ccl), Some(Ordering::Less));
+    assert_eq!(ccl.subset_cmp(&charclass!['c' => 'f']), Some(Ordering::Greater));
+    assert_eq!(ccl.subset_cmp(&ccl.clone()), Some(Ordering::Equal));
+    assert_eq!(ccl.subset_cmp(&charclass!['0' => '9']), None);
+    ```
+    */
+    pub fn subset_cmp(&self, other: &CharClass) -> Option<std::cmp::Ordering> {
+        use std::cmp::Ordering;
+
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.difference_is_empty(other) {
+            Some(Ordering::Less)
+        } else if other.difference_is_empty(self) {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+
+    /** Find the byte index of the first character in `haystack` being member of the class.
+
+    Classes of ASCII characters only are searched byte-wise using a bitmap;
+    with the `simd` feature, classes of up to three ASCII characters are
+    searched using `memchr`.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    assert_eq!(charclass!['0' => '9'].find_in("abc123"), Some(3));
+    assert_eq!(charclass!['€'].find_in("price: 5€"), Some(8));
+    assert_eq!(charclass!['x'].find_in("äöü"), None);
+    ```
+    */
+    pub fn find_in(&self, haystack: &str) -> Option<usize> {
+        match self.ranges.last() {
+            None => None,
+            Some(last) if (*last.end() as u32) < 0x80 => {
+                #[cfg(feature = "simd")]
+                {
+                    let bytes = haystack.as_bytes();
+                    let len = self.len();
 
-impl CharClass {
-    /** Create new empty character class. */
-    pub fn new() -> Self {
-        Self { ranges: Vec::new() }
+                    if len <= 3 {
+                        let mut chars = self
+                            .ranges
+                            .iter()
+                            .flat_map(|r| r.clone())
+                            .map(|ch| ch as u8);
+                        let a = chars.next().unwrap();
+
+                        return match (chars.next(), chars.next()) {
+                            (None, _) => memchr::memchr(a, bytes),
+                            (Some(b), None) => memchr::memchr2(a, b, bytes),
+                            (Some(b), Some(c)) => memchr::memchr3(a, b, c, bytes),
+                        };
+                    }
+                }
+
+                // Non-ASCII bytes are never members, so a byte-wise scan is sufficient
+                let bits = self.to_bitset_256();
+
+                haystack
+                    .bytes()
+                    .position(|b| b < 0x80 && bits[(b >> 6) as usize] & (1 << (b & 63)) != 0)
+            }
+            Some(_) => haystack
+                .char_indices()
+                .find(|(_, ch)| self.contains(*ch))
+                .map(|(idx, _)| idx),
+        }
     }
 
-    /** Retrieve total number of characters in class */
-    pub fn len(&self) -> u32 {
-        self.ranges
-            .iter()
-            .map(|r| *r.end() as u32 - *r.start() as u32 + 1)
-            .sum()
+    /** Split a string into the substrings separated by runs of the class's characters.
+
+    Separator runs at the start or end of `s` are ignored, like with
+    `str::split_whitespace()`, so no empty substrings are yielded.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let sep = charclass![',', ' '];
+    assert_eq!(sep.split("a, b,,c").collect::<Vec<_>>(), vec!["a", "b", "c"]);
+    assert_eq!(sep.split(" ,a ").collect::<Vec<_>>(), vec!["a"]);
+    assert_eq!(sep.split(", ").count(), 0);
+    ```
+    */
+    pub fn split<'a>(&'a self, s: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        s.split(move |ch: char| self.contains(ch))
+            .filter(|part| !part.is_empty())
     }
 
-    /** Dump the character ranges */
-    pub fn dump(&self) {
-        println!("{:p} ranges={}", self, self.ranges.len());
+    /** Trim all leading and trailing characters of the class from a string.
 
-        for i in 0..self.ranges.len() {
-            println!("  {:?}", self.ranges[i]);
-        }
+    Example:
+    ```
+    use charclass::charclass;
+
+    let ccl = charclass![' ', '!', '?'];
+    assert_eq!(ccl.trim_matches("  hi!  "), "hi");
+    assert_eq!(ccl.trim_start_matches("  hi!  "), "hi!  ");
+    assert_eq!(ccl.trim_end_matches("  hi!  "), "  hi");
+    ```
+    */
+    pub fn trim_matches<'a>(&self, s: &'a str) -> &'a str {
+        s.trim_matches(|ch: char| self.contains(ch))
     }
 
-    /** Normalize character-class by removing intersections and coherent ranges. */
-    pub fn normalize(&mut self) {
-        let mut prev_count: usize = 0;
+    /** Trim all leading characters of the class from a string. */
+    pub fn trim_start_matches<'a>(&self, s: &'a str) -> &'a str {
+        s.trim_start_matches(|ch: char| self.contains(ch))
+    }
 
-        while self.ranges.len() != prev_count {
-            prev_count = self.ranges.len();
+    /** Trim all trailing characters of the class from a string. */
+    pub fn trim_end_matches<'a>(&self, s: &'a str) -> &'a str {
+        s.trim_end_matches(|ch: char| self.contains(ch))
+    }
 
-            // First sort all ranges
-            self.ranges.sort_by(|a, b| a.start().cmp(b.start()));
+    /** Replace every character of the class in a string by `replacement`.
 
-            // Then look for intersections
-            for i in 0..self.ranges.len() - 1 {
-                let a = &self.ranges[i];
-                let b = &self.ranges[i + 1];
+    Example:
+    ```
+    use charclass::charclass;
 
-                // Remove intersections
-                if b.start() <= a.end() && b.end() >= a.start() {
-                    if b.end() > a.end() {
-                        self.ranges[i] = *a.start()..=*b.end();
-                    }
+    let digits = charclass!['0' => '9'];
+    assert_eq!(digits.replace("call 0800-123", '#'), "call ####-###");
+    ```
+    */
+    pub fn replace(&self, s: &str, replacement: char) -> String {
+        let mut result = String::with_capacity(s.len());
 
-                    self.ranges.remove(i + 1);
-                    break;
-                }
-                // Merge coherent ranges
-                else if *a.end() as u32 + 1 == *b.start() as u32 {
-                    self.ranges[i] = *a.start()..=*b.end();
-                    self.ranges.remove(i + 1);
-                    break;
-                }
-            }
+        for ch in s.chars() {
+            result.push(if self.contains(ch) { replacement } else { ch });
         }
+
+        result
     }
 
-    /** Negate entire character class */
-    pub fn negate(mut self) -> CharClass {
-        let mut prev_count: usize = 0;
-        let mut start = '\0';
-        let mut end = '\0';
+    /** Count the characters of a string which are members of the class.
 
-        while self.ranges.len() != prev_count {
-            prev_count = self.ranges.len();
+    Example:
+    ```
+    use charclass::charclass;
 
-            for i in 0..self.ranges.len() {
-                let irange = self.ranges[i].clone();
+    let vowels = charclass!['a', 'e', 'i', 'o', 'u'];
+    assert_eq!(vowels.count_in_str("education"), 5);
+    ```
+    */
+    pub fn count_in_str(&self, s: &str) -> usize {
+        s.chars().filter(|ch| self.contains(*ch)).count()
+    }
 
-                if end < *irange.start() {
-                    end = if *irange.start() > '\0' {
-                        std::char::from_u32(*irange.start() as u32 - 1).unwrap()
-                    } else {
-                        '\0'
-                    };
+    /** Find the member character closest in code-point value to `ch`.
 
-                    self.ranges[i] = start..=end;
+    Returns `ch` itself when it is a member, and `None` for an empty class.
+    When two members are equally distant, the lower one is preferred.
 
-                    start = if *irange.end() < std::char::MAX {
-                        std::char::from_u32(*irange.end() as u32 + 1).unwrap()
-                    } else {
-                        std::char::MAX
-                    };
+    Example:
+    ```
+    use charclass::charclass;
 
-                    end = start;
-                } else {
-                    end = if *irange.end() < std::char::MAX {
-                        std::char::from_u32(*irange.end() as u32 + 1).unwrap()
-                    } else {
-                        std::char::MAX
-                    };
+    let ccl = charclass!['d' => 'f', 'j' => 'k'];
+    assert_eq!(ccl.nearest('a'), Some('d'));
+    assert_eq!(ccl.nearest('e'), Some('e'));
+    assert_eq!(ccl.nearest('h'), Some('f'));
+    assert_eq!(ccl.nearest('z'), Some('k'));
+    ```
+    */
+    pub fn nearest(&self, ch: char) -> Option<char> {
+        let idx = self.ranges.partition_point(|r| *r.start() <= ch);
 
-                    self.ranges.remove(i);
-                    break;
+        let below = if idx > 0 {
+            let range = &self.ranges[idx - 1];
+
+            if *range.end() >= ch {
+                return Some(ch);
+            }
+
+            Some(*range.end())
+        } else {
+            None
+        };
+
+        let above = self.ranges.get(idx).map(|r| *r.start());
+
+        match (below, above) {
+            (Some(below), Some(above)) => {
+                if ch as u32 - below as u32 <= above as u32 - ch as u32 {
+                    Some(below)
+                } else {
+                    Some(above)
                 }
             }
+            (below, above) => below.or(above),
         }
+    }
 
-        if end < std::char::MAX {
-            self.ranges.push(end..=std::char::MAX);
-        }
+    /** Check for ranges that are reversed or span into the surrogate gap.
 
-        self.normalize();
-        self
+    This can detect corrupted classes, e.g. from unchecked construction.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    assert!(!charclass!['a' => 'z'].has_invalid_ranges());
+    assert!(CharClass::from_ranges_unchecked(vec!['z'..='a']).has_invalid_ranges());
+    assert!(CharClass::from_ranges_unchecked(vec!['\u{D000}'..='\u{E100}']).has_invalid_ranges());
+    ```
+    */
+    pub fn has_invalid_ranges(&self) -> bool {
+        self.ranges
+            .iter()
+            .any(|r| r.start() > r.end() || (*r.start() <= '\u{D7FF}' && *r.end() >= '\u{E000}'))
     }
 
-    /** Add range to character class. */
-    pub fn add(&mut self, range: CharClassRange) -> u32 {
-        let len = self.len();
-        self.ranges.push(range);
-        self.normalize();
-        self.len() - len
+    /** Check if the class contains every valid Unicode scalar value.
+
+    In contrast to `is_any()`, this doesn't depend on the representation,
+    so it also holds for a single range spanning the surrogate gap.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    assert!(CharClass::any().is_full_unicode());
+    assert!(CharClass::from_ranges_unchecked(vec!['\0'..=char::MAX]).is_full_unicode());
+    assert!(!CharClass::from_ranges_unchecked(vec!['\0'..=char::MAX]).is_any());
+    assert!(!charclass!['a' => 'z'].negate().is_full_unicode());
+    assert_eq!(format!("{:?}", CharClass::any()), ".");
+    ```
+    */
+    pub fn is_full_unicode(&self) -> bool {
+        self.len() == CharClass::SCALAR_VALUE_COUNT
     }
 
-    /** Clears entire range to be empty. */
-    pub fn clear(&mut self) {
-        self.ranges.clear();
+    /** Does this range fit all chars?
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    assert!(CharClass::any().is_any());
+    assert!(!charclass!['a' => 'z'].is_any());
+    ```
+    */
+    pub fn is_any(&self) -> bool {
+        self.ranges == CharClass::any().ranges
     }
+}
 
-    /** Test */
-    pub fn test(&self, range: &CharClassRange) -> bool {
-        self.ranges
-            .binary_search_by(|r| {
-                if r.start() > range.end() {
-                    std::cmp::Ordering::Greater
-                } else if r.end() < range.start() {
-                    std::cmp::Ordering::Less
-                } else {
-                    if range.start() >= r.start() && range.end() <= r.end() {
-                        std::cmp::Ordering::Equal
-                    } else {
-                        std::cmp::Ordering::Less // fixme: Is here also a Greater-case?
-                    }
-                }
-            })
-            .is_ok()
+impl Default for CharClass {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    /** Does this range fit all chars? */
-    fn is_any(&self) -> bool {
-        self.ranges.len() == 1
-            && *self.ranges[0].start() == 0 as char
-            && *self.ranges[0].end() == std::char::MAX
+/** Create character class matching any character from `..`.
+
+Example:
+```
+use charclass::CharClass;
+
+let ccl = CharClass::from(..);
+assert!(ccl.is_any());
+assert!(ccl.contains('\0'));
+assert!(ccl.contains(char::MAX));
+```
+*/
+impl From<std::ops::RangeFull> for CharClass {
+    fn from(_: std::ops::RangeFull) -> Self {
+        CharClass::any()
+    }
+}
+
+/** Create character class from a range of bytes, taken as Latin-1 characters.
+
+A reversed range results in an empty class.
+
+Example:
+```
+use charclass::{charclass, CharClass};
+
+assert_eq!(CharClass::from(b'a'..=b'z'), charclass!['a' => 'z']);
+assert_eq!(CharClass::from(0xc0..=0xff), charclass!['À' => 'ÿ']);
+assert!(CharClass::from(b'z'..=b'a').is_empty());
+```
+*/
+impl From<std::ops::RangeInclusive<u8>> for CharClass {
+    fn from(range: std::ops::RangeInclusive<u8>) -> Self {
+        CharClass::range(char::from(*range.start()), char::from(*range.end()))
+    }
+}
+
+/** Collect the union of character classes, using a k-way merge like `union_iter()`.
+
+Example:
+```
+use charclass::{charclass, CharClass};
+
+let branches = vec![charclass!['a' => 'f'], charclass!['0' => '9'], charclass!['e' => 'k']];
+let ccl: CharClass = branches.into_iter().collect();
+assert_eq!(ccl, charclass!['0' => '9', 'a' => 'k']);
+```
+*/
+impl std::iter::FromIterator<CharClass> for CharClass {
+    fn from_iter<I: IntoIterator<Item = CharClass>>(iter: I) -> Self {
+        let classes: Vec<CharClass> = iter.into_iter().collect();
+        CharClass::union_iter(&classes)
+    }
+}
+
+/** Sum up character classes to their union, normalizing only once.
+
+Example:
+```
+use charclass::{charclass, CharClass};
+
+let classes = vec![charclass!['a' => 'c'], charclass!['x' => 'z'], charclass!['b' => 'd']];
+assert_eq!(classes.iter().sum::<CharClass>(), charclass!['a' => 'd', 'x' => 'z']);
+assert_eq!(classes.into_iter().sum::<CharClass>(), charclass!['a' => 'd', 'x' => 'z']);
+assert_eq!(Vec::<CharClass>::new().into_iter().sum::<CharClass>(), CharClass::new());
+```
+*/
+impl std::iter::Sum for CharClass {
+    fn sum<I: Iterator<Item = CharClass>>(iter: I) -> Self {
+        iter.collect()
+    }
+}
+
+impl<'a> std::iter::Sum<&'a CharClass> for CharClass {
+    fn sum<I: Iterator<Item = &'a CharClass>>(iter: I) -> Self {
+        CharClass::union_iter(iter)
     }
 }
 
+/** Debug character class in bracket notation.
+
+The alternate form (`{:#?}`) prints one range per line, with the code points
+of its characters.
+
+Example:
+```
+use charclass::charclass;
+
+let ccl = charclass!['a' => 'z', '€' => '€'];
+assert_eq!(format!("{:?}", ccl), "[a-z€]");
+assert_eq!(
+    format!("{:#?}", ccl),
+    "[\n    U+0061 'a' - U+007A 'z'\n    U+20AC '€'\n]"
+);
+```
+*/
 impl std::fmt::Debug for CharClass {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         fn escape(ch: char) -> String {
@@ -164,13 +2829,26 @@ impl std::fmt::Debug for CharClass {
                 '\r' => "\\r".to_string(),
                 '\t' => "\\t".to_string(),
                 '\x0b' => "\\v".to_string(),
-                '\\' => "\\\\".to_string(),
+                '\\' | ']' | '-' | '^' => format!("\\{}", ch),
                 _ => format!("{}", ch),
             }
         }
 
-        if self.is_any() {
+        if self.is_full_unicode() {
             write!(f, ".")?;
+        } else if f.alternate() {
+            writeln!(f, "[")?;
+            for range in &self.ranges {
+                let (start, end) = (*range.start(), *range.end());
+                write!(f, "    U+{:04X} {:?}", start as u32, start)?;
+
+                if start < end {
+                    write!(f, " - U+{:04X} {:?}", end as u32, end)?;
+                }
+
+                writeln!(f)?;
+            }
+            write!(f, "]")?;
         } else {
             write!(f, "[")?;
             for range in &self.ranges {
@@ -187,6 +2865,75 @@ impl std::fmt::Debug for CharClass {
     }
 }
 
+/** Display character class in bracket notation.
+
+The alternate form (`{:#}`) prints every non-ASCII or non-printable character
+in its `\u{...}` form, so the output is plain ASCII.
+
+The characters `\`, `]`, `-` and `^` are escaped by a backslash, like in the
+single-line `Debug` output, so both can be parsed back using
+`from_regex_bracket()`. This includes the empty class `[]` and the full
+class `.`.
+
+Example:
+```
+use charclass::{charclass, CharClass};
+
+let ccl = charclass!['a', 'b', 'c', '😀'];
+assert_eq!(format!("{}", ccl), "[a-c😀]");
+assert_eq!(format!("{:#}", ccl), "[a-c\\u{1f600}]");
+
+for ccl in [
+    charclass!['-'],
+    charclass![']'],
+    charclass!['^'],
+    charclass!['\\'],
+    charclass!['a' => 'a', '-' => '-'],
+    charclass!['\\' => '^'],
+    charclass!['\n', ' ', '🦀'],
+    CharClass::new(),
+    CharClass::any(),
+] {
+    assert_eq!(CharClass::from_regex_bracket(&format!("{}", ccl)), Ok(ccl.clone()));
+    assert_eq!(CharClass::from_regex_bracket(&format!("{:#}", ccl)), Ok(ccl.clone()));
+    assert_eq!(CharClass::from_regex_bracket(&format!("{:?}", ccl)), Ok(ccl));
+}
+
+assert_eq!(format!("{}", charclass!['-', 'a']), "[\\-a]");
+assert_eq!(format!("{:?}", charclass![']' => '^']), "[\\]-\\^]");
+```
+*/
+impl std::fmt::Display for CharClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let verbose = f.alternate();
+
+        let write_char = |f: &mut std::fmt::Formatter<'_>, ch: char| {
+            if matches!(ch, '\\' | ']' | '-' | '^') {
+                write!(f, "\\{}", ch)
+            } else if verbose && !(ch.is_ascii_graphic() || ch == ' ') {
+                write!(f, "\\u{{{:x}}}", ch as u32)
+            } else {
+                write!(f, "{}", ch)
+            }
+        };
+
+        if self.is_full_unicode() {
+            return write!(f, ".");
+        }
+
+        write!(f, "[")?;
+        for range in &self.ranges {
+            write_char(f, *range.start())?;
+
+            if range.start() < range.end() {
+                write!(f, "-")?;
+                write_char(f, *range.end())?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+
 impl PartialOrd for CharClass {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         if self.ranges.len() == other.ranges.len() {
@@ -228,7 +2975,142 @@ impl std::ops::AddAssign for CharClass {
     }
 }
 
-// todo: std::ops::Sub is not implemented yet but might be interesting ;)
+/** Add a single character to character class.
+
+Example:
+```
+use charclass::charclass;
+
+let mut ccl = charclass!['a' => 'c'];
+ccl += 'x';
+assert_eq!(ccl, charclass!['a' => 'c', 'x' => 'x']);
+```
+*/
+impl std::ops::AddAssign<char> for CharClass {
+    fn add_assign(&mut self, ch: char) {
+        self.add(ch..=ch);
+    }
+}
+
+/** Add a range to character class.
+
+Example:
+```
+use charclass::charclass;
+
+let mut ccl = charclass!['a' => 'c'];
+ccl += 'd'..='z';
+assert_eq!(ccl, charclass!['a' => 'z']);
+```
+*/
+impl std::ops::AddAssign<CharClassRange> for CharClass {
+    fn add_assign(&mut self, range: CharClassRange) {
+        self.add(range);
+    }
+}
+
+impl std::ops::Sub for CharClass {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let mut ranges = Vec::with_capacity(self.ranges.len());
+        let mut j = 0;
+
+        for range in &self.ranges {
+            // Skip any ranges of other that end before this range
+            while j < other.ranges.len() && other.ranges[j].end() < range.start() {
+                j += 1;
+            }
+
+            let mut start = Some(*range.start());
+
+            for other in &other.ranges[j..] {
+                let from = match start {
+                    Some(from) if other.start() <= range.end() => from,
+                    _ => break,
+                };
+
+                if *other.start() > from {
+                    ranges.push(from..=prev_char(*other.start()).unwrap());
+                }
+
+                start = if other.end() < range.end() {
+                    next_char(*other.end())
+                } else {
+                    None
+                };
+            }
+
+            if let Some(from) = start {
+                ranges.push(from..=*range.end());
+            }
+        }
+
+        let ccl = CharClass::from_vec(ranges);
+        debug_assert!(ccl.is_normalized());
+        ccl
+    }
+}
+
+impl std::ops::SubAssign for CharClass {
+    fn sub_assign(&mut self, other: Self) {
+        *self = std::mem::take(self) - other;
+    }
+}
+
+/** Intersection of two character classes.
+
+Example:
+```
+use charclass::{charclass, CharClass};
+
+assert_eq!(charclass!['a' => 'm'] & charclass!['h' => 'z'], charclass!['h' => 'm']);
+assert_eq!(charclass!['a' => 'c'] & charclass!['x' => 'z'], CharClass::new());
+```
+*/
+impl std::ops::BitAnd for CharClass {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        let mut ranges = Vec::new();
+        intersect_ranges(&self.ranges, &other.ranges, &mut ranges);
+
+        let ccl = CharClass::from_vec(ranges);
+        debug_assert!(ccl.is_normalized());
+        ccl
+    }
+}
+
+impl std::ops::BitAndAssign for CharClass {
+    fn bitand_assign(&mut self, other: Self) {
+        *self = std::mem::take(self) & other;
+    }
+}
+
+/** Symmetric difference of two character classes, holding the characters of exactly one of them.
+
+Example:
+```
+use charclass::{charclass, CharClass};
+
+assert_eq!(charclass!['a' => 'm'] ^ charclass!['h' => 'z'], charclass!['a' => 'g', 'n' => 'z']);
+assert_eq!(charclass!['a' => 'z'] ^ charclass!['a' => 'z'], CharClass::new());
+```
+*/
+impl std::ops::BitXor for CharClass {
+    type Output = Self;
+
+    fn bitxor(self, other: Self) -> Self {
+        let both = self.clone() & other.clone();
+        (self + other) - both
+    }
+}
+
+impl std::ops::BitXorAssign for CharClass {
+    fn bitxor_assign(&mut self, other: Self) {
+        *self = std::mem::take(self) ^ other;
+    }
+}
 
 /** Character-class construction helper-macro
 
@@ -237,10 +3119,15 @@ Example:
 use charclass::charclass;
 
 let ccl = charclass!['A' => 'Z', 'a' => 'z'] + charclass!['_'];
+assert!(charclass![..].is_any());
 ```
 */
 #[macro_export]
 macro_rules! charclass {
+    ( .. ) => {
+        $crate::CharClass::any()
+    };
+
     ( $( $from:expr => $to:expr ),+ ) => {
         {
             let mut ccl = $crate::CharClass::new();
@@ -258,6 +3145,41 @@ macro_rules! charclass {
     };
 }
 
+/** Character-class construction helper-macro for bytes, taken as Latin-1 characters
+
+Ranges of bytes and single bytes can be mixed.
+
+Example:
+```
+use charclass::{charclass, charclass_bytes};
+
+let ccl = charclass_bytes![0x41 => 0x5A, 0x5F, b'a' => b'z'];
+assert_eq!(ccl, charclass!['A' => 'Z', '_' => '_', 'a' => 'z']);
+assert_eq!(charclass_bytes![0xe4], charclass!['ä']);
+```
+*/
+#[macro_export]
+macro_rules! charclass_bytes {
+    ( @range $from:expr ) => {
+        $from..=$from
+    };
+
+    ( @range $from:expr, $to:expr ) => {
+        $from..=$to
+    };
+
+    ( $( $from:expr $( => $to:expr )? ),+ ) => {
+        {
+            let mut ccl = $crate::CharClass::new();
+            $(
+                let range: std::ops::RangeInclusive<u8> = $crate::charclass_bytes!(@range $from $(, $to)?);
+                ccl += $crate::CharClass::from(range);
+            )*
+            ccl
+        }
+    };
+}
+
 pub fn ccl_test() {
     let mut ccl = CharClass::new();
     ccl.add('a'..='c');
@@ -277,7 +3199,7 @@ pub fn ccl_test() {
         println!("{}: {}", c, ccl.test(&(c..=c)));
     }
 
-    for rg in vec!['k'..='v', 'l'..='o', 'a'..='d', 'a'..='b', 'k'..='x'] {
+    for rg in ['k'..='v', 'l'..='o', 'a'..='d', 'a'..='b', 'k'..='x'] {
         println!("{:?} {}", &rg, ccl.test(&rg));
     }
 
@@ -287,3 +3209,48 @@ pub fn ccl_test() {
     ccl += t;
     ccl.dump();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize_calls<F: FnOnce()>(f: F) -> usize {
+        NORMALIZE_CALLS.with(|calls| calls.set(0));
+        f();
+        NORMALIZE_CALLS.with(|calls| calls.get())
+    }
+
+    fn len_at_least_visits(ccl: &CharClass, n: u32) -> (bool, usize) {
+        LEN_AT_LEAST_VISITS.with(|visits| visits.set(0));
+        let result = ccl.len_at_least(n);
+        (result, LEN_AT_LEAST_VISITS.with(|visits| visits.get()))
+    }
+
+    #[test]
+    fn extend_sorted_normalizes_only_unsorted_input() {
+        let mut ccl = charclass!['a' => 'c'];
+
+        assert_eq!(
+            normalize_calls(|| ccl.extend_sorted(['d'..='f', 'e'..='h', 'x'..='z'])),
+            0
+        );
+        assert_eq!(ccl, charclass!['a' => 'h', 'x' => 'z']);
+
+        assert_eq!(
+            normalize_calls(|| ccl.extend_sorted(['m'..='n', 'b'..='i'])),
+            1
+        );
+        assert_eq!(ccl, charclass!['a' => 'i', 'm' => 'n', 'x' => 'z']);
+    }
+
+    #[test]
+    fn len_at_least_stops_early() {
+        let ccl = charclass!['a' => 'c', 'e' => 'g', 'i' => 'k', 'm' => 'o'];
+
+        assert_eq!(len_at_least_visits(&ccl, 0), (true, 0));
+        assert_eq!(len_at_least_visits(&ccl, 3), (true, 1));
+        assert_eq!(len_at_least_visits(&ccl, 4), (true, 2));
+        assert_eq!(len_at_least_visits(&ccl, 12), (true, 4));
+        assert_eq!(len_at_least_visits(&ccl, 13), (false, 4));
+    }
+}
@@ -0,0 +1,287 @@
+//! Parsing character-classes from bracket expressions
+use super::CharClass;
+
+type Chars<'a> = std::iter::Peekable<std::str::CharIndices<'a>>;
+
+/// Error when parsing a character-class
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The expression doesn't start with `[`
+    MissingOpeningBracket,
+    /// The expression isn't terminated by `]`
+    MissingClosingBracket,
+    /// A range where start is greater than end
+    InvalidRange(char, char),
+    /// An invalid escape sequence at the given byte position
+    InvalidEscape(usize),
+    /// Unexpected characters after the closing `]` at the given byte position
+    TrailingCharacters(usize),
+    /// An unexpected character at the given byte position
+    UnexpectedCharacter(usize),
+    /// A number which isn't a valid Unicode scalar value
+    InvalidCodePoint(u32),
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingOpeningBracket => write!(f, "Expecting '[' at start of character-class"),
+            Self::MissingClosingBracket => write!(f, "Missing ']' to close character-class"),
+            Self::InvalidRange(start, end) => {
+                write!(
+                    f,
+                    "Invalid range {:?}-{:?}, start is greater than end",
+                    start, end
+                )
+            }
+            Self::InvalidEscape(pos) => write!(f, "Invalid escape sequence at position {}", pos),
+            Self::TrailingCharacters(pos) => {
+                write!(f, "Unexpected characters at position {}", pos)
+            }
+            Self::UnexpectedCharacter(pos) => {
+                write!(f, "Unexpected character at position {}", pos)
+            }
+            Self::InvalidCodePoint(cp) => write!(f, "Invalid code point {:#x}", cp),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/** Parse an escape sequence, the leading backslash at `pos` was already consumed. */
+fn parse_escape(chars: &mut Chars, pos: usize) -> Result<char, ParseError> {
+    fn hex(chars: &mut Chars, pos: usize, count: Option<usize>) -> Result<char, ParseError> {
+        let mut value: u32 = 0;
+        let mut digits = 0;
+
+        // Braced escapes like `\u{..}` take up to the 6 digits of `char::MAX`
+        let max = count.unwrap_or(6);
+
+        while digits < max {
+            match chars.peek().and_then(|(_, ch)| ch.to_digit(16)) {
+                Some(digit) => {
+                    value = value * 16 + digit;
+                    digits += 1;
+                    chars.next();
+                }
+                _ => break,
+            }
+        }
+
+        if digits == 0 || count.is_some_and(|count| digits != count) {
+            return Err(ParseError::InvalidEscape(pos));
+        }
+
+        std::char::from_u32(value).ok_or(ParseError::InvalidEscape(pos))
+    }
+
+    let (_, ch) = chars.next().ok_or(ParseError::InvalidEscape(pos))?;
+
+    Ok(match ch {
+        'a' => '\x07',
+        'b' => '\x08',
+        'f' => '\x0c',
+        'n' => '\n',
+        'r' => '\r',
+        't' => '\t',
+        'v' => '\x0b',
+        '0' => '\0',
+        'x' | 'u' if matches!(chars.peek(), Some((_, '{'))) => {
+            chars.next();
+            let ch = hex(chars, pos, None)?;
+
+            if !matches!(chars.next(), Some((_, '}'))) {
+                return Err(ParseError::InvalidEscape(pos));
+            }
+
+            ch
+        }
+        'x' => hex(chars, pos, Some(2))?,
+        'u' => return Err(ParseError::InvalidEscape(pos)),
+        ch => ch,
+    })
+}
+
+/** Parse a single, possibly escaped character. */
+fn parse_char(chars: &mut Chars) -> Result<Option<(usize, char)>, ParseError> {
+    match chars.next() {
+        Some((pos, '\\')) => Ok(Some((pos, parse_escape(chars, pos)?))),
+        other => Ok(other),
+    }
+}
+
+impl CharClass {
+    /** Parse character class from a regular expression bracket expression.
+
+    The expression must be enclosed by brackets, and can be negated by a
+    leading `^`. Following the POSIX/PCRE conventions, a `]` as the first
+    character and a `-` at the start or end are taken literally. Escape
+    sequences like `\n`, `\x41`, `\x{41}` or `\u{20AC}` are supported, and a
+    backslash followed by any other character stands for that character.
+
+    The forms `Display` prints for the empty and the full class, `[]` and `.`,
+    are accepted as well.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    assert_eq!(CharClass::from_regex_bracket("[a-z]"), Ok(charclass!['a' => 'z']));
+    assert_eq!(CharClass::from_regex_bracket("[]a]"), Ok(charclass![']', 'a']));
+    assert_eq!(CharClass::from_regex_bracket("[a-]"), Ok(charclass!['-', 'a']));
+    assert_eq!(CharClass::from_regex_bracket("[-a]"), Ok(charclass!['-', 'a']));
+    assert_eq!(CharClass::from_regex_bracket("[\\]\\-]"), Ok(charclass![']', '-']));
+    assert_eq!(
+        CharClass::from_regex_bracket("[^a]"),
+        Ok(charclass!['a'].negate())
+    );
+    assert_eq!(CharClass::from_regex_bracket("[\\x41\\x{42}\\u{43}]"), Ok(charclass!['A' => 'C']));
+    assert_eq!(CharClass::from_regex_bracket("[]"), Ok(CharClass::new()));
+    assert_eq!(CharClass::from_regex_bracket("."), Ok(CharClass::any()));
+    assert!(CharClass::from_regex_bracket("[z-a]").is_err());
+    assert!(CharClass::from_regex_bracket("[a").is_err());
+    ```
+    */
+    pub fn from_regex_bracket(s: &str) -> Result<CharClass, ParseError> {
+        match s {
+            "[]" => return Ok(CharClass::new()),
+            "." => return Ok(CharClass::any()),
+            _ => {}
+        }
+
+        let mut chars = s.char_indices().peekable();
+
+        if !matches!(chars.next(), Some((_, '['))) {
+            return Err(ParseError::MissingOpeningBracket);
+        }
+
+        let negate = chars.next_if(|(_, ch)| *ch == '^').is_some();
+        let mut ccl = CharClass::new();
+        let mut first = true;
+
+        loop {
+            let start = match chars.peek() {
+                Some((_, ']')) if !first => {
+                    chars.next();
+                    break;
+                }
+                Some(_) => parse_char(&mut chars)?.unwrap().1,
+                None => return Err(ParseError::MissingClosingBracket),
+            };
+
+            first = false;
+
+            // A '-' is only taken as range operator when not followed by ']'
+            let mut ahead = chars.clone();
+
+            if matches!(ahead.next(), Some((_, '-'))) && !matches!(ahead.peek(), Some((_, ']'))) {
+                chars.next();
+
+                let end = match parse_char(&mut chars)? {
+                    Some((_, end)) => end,
+                    None => return Err(ParseError::MissingClosingBracket),
+                };
+
+                if start > end {
+                    return Err(ParseError::InvalidRange(start, end));
+                }
+
+                ccl.add(start..=end);
+            } else {
+                ccl.add(start..=start);
+            }
+        }
+
+        if let Some((pos, _)) = chars.next() {
+            return Err(ParseError::TrailingCharacters(pos));
+        }
+
+        Ok(if negate { ccl.negate() } else { ccl })
+    }
+
+    /** Parse character class from the range pairs form of `to_range_pairs_string()`.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    let ccl = charclass!['a' => 'z', '€' => '€'];
+    let s = ccl.to_range_pairs_string();
+    assert_eq!(s, "[[97,122],[8364,8364]]");
+    assert_eq!(CharClass::from_range_pairs_string(&s), Ok(ccl));
+    assert_eq!(CharClass::from_range_pairs_string("[]"), Ok(CharClass::new()));
+    assert!(CharClass::from_range_pairs_string("[[55296,55296]]").is_err());
+    assert!(CharClass::from_range_pairs_string("[[1,2]").is_err());
+    ```
+    */
+    pub fn from_range_pairs_string(s: &str) -> Result<CharClass, ParseError> {
+        fn expect(chars: &mut Chars, expect: char) -> Result<(), ParseError> {
+            match chars.next() {
+                Some((_, ch)) if ch == expect => Ok(()),
+                Some((pos, _)) => Err(ParseError::UnexpectedCharacter(pos)),
+                None => Err(ParseError::MissingClosingBracket),
+            }
+        }
+
+        fn number(chars: &mut Chars) -> Result<char, ParseError> {
+            let mut value: u32 = 0;
+            let mut digits = 0;
+
+            while let Some((pos, ch)) = chars.peek().copied() {
+                match ch.to_digit(10) {
+                    Some(digit) => {
+                        value = value
+                            .checked_mul(10)
+                            .and_then(|value| value.checked_add(digit))
+                            .ok_or(ParseError::UnexpectedCharacter(pos))?;
+                        digits += 1;
+                        chars.next();
+                    }
+                    None if digits > 0 => break,
+                    None => return Err(ParseError::UnexpectedCharacter(pos)),
+                }
+            }
+
+            if digits == 0 {
+                return Err(ParseError::MissingClosingBracket);
+            }
+
+            std::char::from_u32(value).ok_or(ParseError::InvalidCodePoint(value))
+        }
+
+        let mut chars = s.char_indices().peekable();
+        let mut ccl = CharClass::new();
+
+        if !matches!(chars.next(), Some((_, '['))) {
+            return Err(ParseError::MissingOpeningBracket);
+        }
+
+        if chars.next_if(|(_, ch)| *ch == ']').is_none() {
+            loop {
+                expect(&mut chars, '[')?;
+                let start = number(&mut chars)?;
+                expect(&mut chars, ',')?;
+                let end = number(&mut chars)?;
+                expect(&mut chars, ']')?;
+
+                if start > end {
+                    return Err(ParseError::InvalidRange(start, end));
+                }
+
+                ccl.add(start..=end);
+
+                if chars.next_if(|(_, ch)| *ch == ']').is_some() {
+                    break;
+                }
+
+                expect(&mut chars, ',')?;
+            }
+        }
+
+        if let Some((pos, _)) = chars.next() {
+            return Err(ParseError::TrailingCharacters(pos));
+        }
+
+        Ok(ccl)
+    }
+}
@@ -0,0 +1,59 @@
+//! Guarded mutable access to the ranges of a character-class
+use super::{CharClass, CharClassRange};
+
+/** Mutable access to the ranges of a character class, as returned by `CharClass::ranges_mut()`.
+
+The ranges can be edited freely, and are normalized again when the guard
+is dropped.
+*/
+pub struct RangesMut<'a> {
+    ccl: &'a mut CharClass,
+}
+
+impl std::ops::Deref for RangesMut<'_> {
+    type Target = Vec<CharClassRange>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.ccl.ranges
+    }
+}
+
+impl std::ops::DerefMut for RangesMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.ccl.ranges
+    }
+}
+
+impl Drop for RangesMut<'_> {
+    fn drop(&mut self) {
+        self.ccl.normalize();
+    }
+}
+
+impl CharClass {
+    /** Get mutable access to the ranges, normalizing them once the access ends.
+
+    This allows for bulk edits at the cost of a single normalization.
+
+    Example:
+    ```
+    use charclass::charclass;
+
+    let mut ccl = charclass!['a' => 'c', 'x' => 'z'];
+
+    {
+        let mut ranges = ccl.ranges_mut();
+        ranges[0] = 'a'..='m';
+        ranges.push('d'..='p');
+        ranges.push('0'..='9');
+        ranges.retain(|r| *r.start() != 'x');
+    }
+
+    assert_eq!(ccl, charclass!['0' => '9', 'a' => 'p']);
+    assert_eq!(ccl.ranges(), &['0'..='9', 'a'..='p']);
+    ```
+    */
+    pub fn ranges_mut(&mut self) -> RangesMut<'_> {
+        RangesMut { ccl: self }
+    }
+}
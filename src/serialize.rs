@@ -0,0 +1,123 @@
+//! Serialization support using serde
+//!
+//! A character-class is serialized as a sequence of `(start, end)` pairs,
+//! holding the inclusive code points of each range as `u32`. This keeps
+//! binary formats compact and independent of how a backend encodes `char`.
+//! For example, with bincode `[a-z]` is a `u64` length prefix of 1, followed
+//! by the little-endian `u32` values 0x61 and 0x7a.
+//!
+//! When deserializing from human-readable formats, a bracket expression
+//! string like `"[a-z]"` is accepted as well.
+use super::CharClass;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/** Serialize character class as sequence of `(u32, u32)` pairs.
+
+Example:
+```
+use charclass::charclass;
+
+let ccl = charclass!['a' => 'z', '€' => '€'];
+let bytes = bincode::serialize(&ccl).unwrap();
+assert_eq!(bytes.len(), 8 + 2 * 8);
+assert_eq!(&bytes[8..16], &[0x61, 0, 0, 0, 0x7a, 0, 0, 0]);
+assert_eq!(bincode::deserialize::<charclass::CharClass>(&bytes).unwrap(), ccl);
+```
+*/
+impl Serialize for CharClass {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            self.ranges
+                .iter()
+                .map(|r| (*r.start() as u32, *r.end() as u32)),
+        )
+    }
+}
+
+/** Visitor accepting either the sequence of pairs, or a bracket expression string. */
+struct CharClassVisitor;
+
+impl<'de> de::Visitor<'de> for CharClassVisitor {
+    type Value = CharClass;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "a sequence of (u32, u32) pairs or a bracket expression string"
+        )
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<CharClass, A::Error> {
+        let mut ranges = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some((start, end)) = seq.next_element::<(u32, u32)>()? {
+            let range = match (std::char::from_u32(start), std::char::from_u32(end)) {
+                (Some(start), Some(end)) if start <= end => start..=end,
+                _ => {
+                    return Err(de::Error::custom(format!(
+                        "invalid character range {:#x}-{:#x}",
+                        start, end
+                    )))
+                }
+            };
+
+            ranges.push(range);
+        }
+
+        let mut ccl = CharClass::from_vec(ranges);
+        ccl.canonicalize();
+        Ok(ccl)
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<CharClass, E> {
+        CharClass::from_regex_bracket(s).map_err(E::custom)
+    }
+}
+
+/** Deserialize character class from a sequence of `(u32, u32)` pairs.
+
+The ranges are validated and canonicalized, so they may arrive in any order,
+and data from older versions holding a single range spanning the surrogate
+gap results in the same class.
+Human-readable formats additionally accept the bracket expression form of
+`Display`, like `"[a-z]"`, which is handy in hand-edited configuration files.
+
+Example:
+```
+use charclass::{charclass, CharClass};
+use serde::de::{value, Deserialize, IntoDeserializer};
+
+let from_str = CharClass::deserialize(
+    IntoDeserializer::<value::Error>::into_deserializer("[a-z€]"),
+);
+let from_seq = CharClass::deserialize(
+    IntoDeserializer::<value::Error>::into_deserializer(vec![vec![0x20ac_u32, 0x20ac], vec![0x61, 0x7a]]),
+);
+
+assert_eq!(from_str, Ok(charclass!['a' => 'z', '€' => '€']));
+assert_eq!(from_seq, Ok(charclass!['a' => 'z', '€' => '€']));
+
+let invalid = CharClass::deserialize(IntoDeserializer::<value::Error>::into_deserializer("[z-a]"));
+assert!(invalid.is_err());
+
+// The string forms of the empty and the full class
+for ccl in [CharClass::new(), CharClass::any()] {
+    let s = ccl.to_string();
+    let parsed = CharClass::deserialize(IntoDeserializer::<value::Error>::into_deserializer(s.as_str()));
+    assert_eq!(parsed, Ok(ccl));
+}
+
+// Legacy form of the class matching any character
+let legacy = bincode::serialize(&vec![(0u32, 0x10ffffu32)]).unwrap();
+assert_eq!(bincode::deserialize::<CharClass>(&legacy).unwrap(), CharClass::any());
+```
+*/
+impl<'de> Deserialize<'de> for CharClass {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(CharClassVisitor)
+        } else {
+            deserializer.deserialize_seq(CharClassVisitor)
+        }
+    }
+}
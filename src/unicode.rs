@@ -0,0 +1,143 @@
+//! Character-classes from Unicode properties
+#[rustfmt::skip]
+mod tables;
+
+use super::{valid_char_ranges, CharClass};
+
+/** Compare property value names loosely, ignoring case, whitespace, `_` and `-`. */
+fn loose_eq(a: &str, b: &str) -> bool {
+    let strip = |s: &str| {
+        s.chars()
+            .filter(|ch| !(ch.is_whitespace() || *ch == '_' || *ch == '-'))
+            .flat_map(|ch| ch.to_lowercase())
+            .collect::<String>()
+    };
+
+    strip(a) == strip(b)
+}
+
+impl CharClass {
+    /** Create character class of the Unicode `XID_Start` property.
+
+    These are the characters starting an identifier, according to UAX #31.
+
+    Example:
+    ```
+    use charclass::CharClass;
+
+    let ccl = CharClass::xid_start();
+    assert!(ccl.contains('a'));
+    assert!(ccl.contains('α'));
+    assert!(ccl.contains('ж'));
+    assert!(ccl.contains('中'));
+    assert!(ccl.contains('ا'));
+    assert!(!ccl.contains('_'));
+    assert!(!ccl.contains('1'));
+    ```
+    */
+    pub fn xid_start() -> Self {
+        Self::from_vec(tables::XID_START.to_vec())
+    }
+
+    /** Create character class of the Unicode `XID_Continue` property.
+
+    These are the characters continuing an identifier, according to UAX #31.
+
+    Example:
+    ```
+    use charclass::CharClass;
+
+    let ccl = CharClass::xid_continue();
+    assert!(ccl.contains('_'));
+    assert!(ccl.contains('1'));
+    assert!(ccl.contains('٣'));
+    assert!(ccl.contains('\u{301}'));
+    assert!(!ccl.contains('-'));
+    assert!(CharClass::xid_start().difference_is_empty(&ccl));
+    ```
+    */
+    pub fn xid_continue() -> Self {
+        Self::from_vec(tables::XID_CONTINUE.to_vec())
+    }
+
+    /** Create character class of the Unicode `White_Space` property.
+
+    In contrast to the ASCII whitespace of `\s` in many regular expression
+    dialects, this includes characters like U+00A0 (no-break space), U+2028
+    (line separator), U+2029 (paragraph separator) and the spaces from
+    U+2000 to U+200A. It is close to `char::is_whitespace()`, but follows
+    the property tables of the Unicode version used by this crate.
+
+    Example:
+    ```
+    use charclass::CharClass;
+
+    let ccl = CharClass::unicode_whitespace();
+    assert!(ccl.contains(' '));
+    assert!(ccl.contains('\u{A0}'));
+    assert!(ccl.contains('\u{2028}'));
+    assert!(ccl.contains('\u{2029}'));
+    assert!(ccl.test(&('\u{2000}'..='\u{200A}')));
+    assert!(!ccl.contains('\u{200B}'));
+    ```
+    */
+    pub fn unicode_whitespace() -> Self {
+        Self::from_vec(tables::WHITE_SPACE.to_vec())
+    }
+
+    /** Create character class of all characters in the given Unicode script.
+
+    The script name is matched loosely, so `"Old_Italic"`, `"old italic"` and
+    `"OldItalic"` are equivalent. Returns `None` for unknown scripts.
+
+    Example:
+    ```
+    use charclass::CharClass;
+
+    let greek = CharClass::from_script("Greek").unwrap();
+    assert!(greek.contains('α'));
+    assert!(greek.contains('Ω'));
+    assert!(!greek.contains('a'));
+
+    assert!(CharClass::from_script("han").unwrap().contains('中'));
+    assert!(CharClass::from_script("Old Italic").is_some());
+
+    // Scripts added in Unicode 17.0
+    assert!(CharClass::from_script("Sidetic").unwrap().contains('\u{10940}'));
+    assert!(CharClass::from_script("Tai Yo").is_some());
+    assert!(CharClass::from_script("Klingon").is_none());
+    ```
+    */
+    pub fn from_script(name: &str) -> Option<Self> {
+        tables::SCRIPTS
+            .iter()
+            .find(|(script, _)| loose_eq(script, name))
+            .map(|(_, ranges)| Self::from_vec(ranges.to_vec()))
+    }
+
+    /** Create character class of all characters in the given Unicode block.
+
+    The block name is matched loosely, like in `from_script()`. Returns
+    `None` for unknown blocks. As surrogates are no valid characters, the
+    surrogate blocks result in an empty class.
+
+    Example:
+    ```
+    use charclass::{charclass, CharClass};
+
+    assert_eq!(
+        CharClass::from_block("Basic Latin"),
+        Some(charclass!['\0' => '\u{7F}'])
+    );
+    assert!(CharClass::from_block("cyrillic").unwrap().contains('ж'));
+    assert!(CharClass::from_block("High Surrogates").unwrap().is_empty());
+    assert!(CharClass::from_block("Klingon").is_none());
+    ```
+    */
+    pub fn from_block(name: &str) -> Option<Self> {
+        tables::BLOCKS
+            .iter()
+            .find(|(block, _, _)| loose_eq(block, name))
+            .map(|(_, start, end)| Self::from_vec(valid_char_ranges(*start, *end).collect()))
+    }
+}
@@ -0,0 +1,3551 @@
+//! Unicode tables, generated by scripts/unicode_tables.py
+//!
+//! DO NOT EDIT THIS FILE MANUALLY.
+//! Unicode version: 17.0.0
+use crate::CharClassRange;
+
+pub(crate) static XID_START: &[CharClassRange] = &[
+    '\u{41}'..='\u{5a}',
+    '\u{61}'..='\u{7a}',
+    '\u{aa}'..='\u{aa}',
+    '\u{b5}'..='\u{b5}',
+    '\u{ba}'..='\u{ba}',
+    '\u{c0}'..='\u{d6}',
+    '\u{d8}'..='\u{f6}',
+    '\u{f8}'..='\u{2c1}',
+    '\u{2c6}'..='\u{2d1}',
+    '\u{2e0}'..='\u{2e4}',
+    '\u{2ec}'..='\u{2ec}',
+    '\u{2ee}'..='\u{2ee}',
+    '\u{370}'..='\u{374}',
+    '\u{376}'..='\u{377}',
+    '\u{37b}'..='\u{37d}',
+    '\u{37f}'..='\u{37f}',
+    '\u{386}'..='\u{386}',
+    '\u{388}'..='\u{38a}',
+    '\u{38c}'..='\u{38c}',
+    '\u{38e}'..='\u{3a1}',
+    '\u{3a3}'..='\u{3f5}',
+    '\u{3f7}'..='\u{481}',
+    '\u{48a}'..='\u{52f}',
+    '\u{531}'..='\u{556}',
+    '\u{559}'..='\u{559}',
+    '\u{560}'..='\u{588}',
+    '\u{5d0}'..='\u{5ea}',
+    '\u{5ef}'..='\u{5f2}',
+    '\u{620}'..='\u{64a}',
+    '\u{66e}'..='\u{66f}',
+    '\u{671}'..='\u{6d3}',
+    '\u{6d5}'..='\u{6d5}',
+    '\u{6e5}'..='\u{6e6}',
+    '\u{6ee}'..='\u{6ef}',
+    '\u{6fa}'..='\u{6fc}',
+    '\u{6ff}'..='\u{6ff}',
+    '\u{710}'..='\u{710}',
+    '\u{712}'..='\u{72f}',
+    '\u{74d}'..='\u{7a5}',
+    '\u{7b1}'..='\u{7b1}',
+    '\u{7ca}'..='\u{7ea}',
+    '\u{7f4}'..='\u{7f5}',
+    '\u{7fa}'..='\u{7fa}',
+    '\u{800}'..='\u{815}',
+    '\u{81a}'..='\u{81a}',
+    '\u{824}'..='\u{824}',
+    '\u{828}'..='\u{828}',
+    '\u{840}'..='\u{858}',
+    '\u{860}'..='\u{86a}',
+    '\u{870}'..='\u{887}',
+    '\u{889}'..='\u{88f}',
+    '\u{8a0}'..='\u{8c9}',
+    '\u{904}'..='\u{939}',
+    '\u{93d}'..='\u{93d}',
+    '\u{950}'..='\u{950}',
+    '\u{958}'..='\u{961}',
+    '\u{971}'..='\u{980}',
+    '\u{985}'..='\u{98c}',
+    '\u{98f}'..='\u{990}',
+    '\u{993}'..='\u{9a8}',
+    '\u{9aa}'..='\u{9b0}',
+    '\u{9b2}'..='\u{9b2}',
+    '\u{9b6}'..='\u{9b9}',
+    '\u{9bd}'..='\u{9bd}',
+    '\u{9ce}'..='\u{9ce}',
+    '\u{9dc}'..='\u{9dd}',
+    '\u{9df}'..='\u{9e1}',
+    '\u{9f0}'..='\u{9f1}',
+    '\u{9fc}'..='\u{9fc}',
+    '\u{a05}'..='\u{a0a}',
+    '\u{a0f}'..='\u{a10}',
+    '\u{a13}'..='\u{a28}',
+    '\u{a2a}'..='\u{a30}',
+    '\u{a32}'..='\u{a33}',
+    '\u{a35}'..='\u{a36}',
+    '\u{a38}'..='\u{a39}',
+    '\u{a59}'..='\u{a5c}',
+    '\u{a5e}'..='\u{a5e}',
+    '\u{a72}'..='\u{a74}',
+    '\u{a85}'..='\u{a8d}',
+    '\u{a8f}'..='\u{a91}',
+    '\u{a93}'..='\u{aa8}',
+    '\u{aaa}'..='\u{ab0}',
+    '\u{ab2}'..='\u{ab3}',
+    '\u{ab5}'..='\u{ab9}',
+    '\u{abd}'..='\u{abd}',
+    '\u{ad0}'..='\u{ad0}',
+    '\u{ae0}'..='\u{ae1}',
+    '\u{af9}'..='\u{af9}',
+    '\u{b05}'..='\u{b0c}',
+    '\u{b0f}'..='\u{b10}',
+    '\u{b13}'..='\u{b28}',
+    '\u{b2a}'..='\u{b30}',
+    '\u{b32}'..='\u{b33}',
+    '\u{b35}'..='\u{b39}',
+    '\u{b3d}'..='\u{b3d}',
+    '\u{b5c}'..='\u{b5d}',
+    '\u{b5f}'..='\u{b61}',
+    '\u{b71}'..='\u{b71}',
+    '\u{b83}'..='\u{b83}',
+    '\u{b85}'..='\u{b8a}',
+    '\u{b8e}'..='\u{b90}',
+    '\u{b92}'..='\u{b95}',
+    '\u{b99}'..='\u{b9a}',
+    '\u{b9c}'..='\u{b9c}',
+    '\u{b9e}'..='\u{b9f}',
+    '\u{ba3}'..='\u{ba4}',
+    '\u{ba8}'..='\u{baa}',
+    '\u{bae}'..='\u{bb9}',
+    '\u{bd0}'..='\u{bd0}',
+    '\u{c05}'..='\u{c0c}',
+    '\u{c0e}'..='\u{c10}',
+    '\u{c12}'..='\u{c28}',
+    '\u{c2a}'..='\u{c39}',
+    '\u{c3d}'..='\u{c3d}',
+    '\u{c58}'..='\u{c5a}',
+    '\u{c5c}'..='\u{c5d}',
+    '\u{c60}'..='\u{c61}',
+    '\u{c80}'..='\u{c80}',
+    '\u{c85}'..='\u{c8c}',
+    '\u{c8e}'..='\u{c90}',
+    '\u{c92}'..='\u{ca8}',
+    '\u{caa}'..='\u{cb3}',
+    '\u{cb5}'..='\u{cb9}',
+    '\u{cbd}'..='\u{cbd}',
+    '\u{cdc}'..='\u{cde}',
+    '\u{ce0}'..='\u{ce1}',
+    '\u{cf1}'..='\u{cf2}',
+    '\u{d04}'..='\u{d0c}',
+    '\u{d0e}'..='\u{d10}',
+    '\u{d12}'..='\u{d3a}',
+    '\u{d3d}'..='\u{d3d}',
+    '\u{d4e}'..='\u{d4e}',
+    '\u{d54}'..='\u{d56}',
+    '\u{d5f}'..='\u{d61}',
+    '\u{d7a}'..='\u{d7f}',
+    '\u{d85}'..='\u{d96}',
+    '\u{d9a}'..='\u{db1}',
+    '\u{db3}'..='\u{dbb}',
+    '\u{dbd}'..='\u{dbd}',
+    '\u{dc0}'..='\u{dc6}',
+    '\u{e01}'..='\u{e30}',
+    '\u{e32}'..='\u{e32}',
+    '\u{e40}'..='\u{e46}',
+    '\u{e81}'..='\u{e82}',
+    '\u{e84}'..='\u{e84}',
+    '\u{e86}'..='\u{e8a}',
+    '\u{e8c}'..='\u{ea3}',
+    '\u{ea5}'..='\u{ea5}',
+    '\u{ea7}'..='\u{eb0}',
+    '\u{eb2}'..='\u{eb2}',
+    '\u{ebd}'..='\u{ebd}',
+    '\u{ec0}'..='\u{ec4}',
+    '\u{ec6}'..='\u{ec6}',
+    '\u{edc}'..='\u{edf}',
+    '\u{f00}'..='\u{f00}',
+    '\u{f40}'..='\u{f47}',
+    '\u{f49}'..='\u{f6c}',
+    '\u{f88}'..='\u{f8c}',
+    '\u{1000}'..='\u{102a}',
+    '\u{103f}'..='\u{103f}',
+    '\u{1050}'..='\u{1055}',
+    '\u{105a}'..='\u{105d}',
+    '\u{1061}'..='\u{1061}',
+    '\u{1065}'..='\u{1066}',
+    '\u{106e}'..='\u{1070}',
+    '\u{1075}'..='\u{1081}',
+    '\u{108e}'..='\u{108e}',
+    '\u{10a0}'..='\u{10c5}',
+    '\u{10c7}'..='\u{10c7}',
+    '\u{10cd}'..='\u{10cd}',
+    '\u{10d0}'..='\u{10fa}',
+    '\u{10fc}'..='\u{1248}',
+    '\u{124a}'..='\u{124d}',
+    '\u{1250}'..='\u{1256}',
+    '\u{1258}'..='\u{1258}',
+    '\u{125a}'..='\u{125d}',
+    '\u{1260}'..='\u{1288}',
+    '\u{128a}'..='\u{128d}',
+    '\u{1290}'..='\u{12b0}',
+    '\u{12b2}'..='\u{12b5}',
+    '\u{12b8}'..='\u{12be}',
+    '\u{12c0}'..='\u{12c0}',
+    '\u{12c2}'..='\u{12c5}',
+    '\u{12c8}'..='\u{12d6}',
+    '\u{12d8}'..='\u{1310}',
+    '\u{1312}'..='\u{1315}',
+    '\u{1318}'..='\u{135a}',
+    '\u{1380}'..='\u{138f}',
+    '\u{13a0}'..='\u{13f5}',
+    '\u{13f8}'..='\u{13fd}',
+    '\u{1401}'..='\u{166c}',
+    '\u{166f}'..='\u{167f}',
+    '\u{1681}'..='\u{169a}',
+    '\u{16a0}'..='\u{16ea}',
+    '\u{16ee}'..='\u{16f8}',
+    '\u{1700}'..='\u{1711}',
+    '\u{171f}'..='\u{1731}',
+    '\u{1740}'..='\u{1751}',
+    '\u{1760}'..='\u{176c}',
+    '\u{176e}'..='\u{1770}',
+    '\u{1780}'..='\u{17b3}',
+    '\u{17d7}'..='\u{17d7}',
+    '\u{17dc}'..='\u{17dc}',
+    '\u{1820}'..='\u{1878}',
+    '\u{1880}'..='\u{18a8}',
+    '\u{18aa}'..='\u{18aa}',
+    '\u{18b0}'..='\u{18f5}',
+    '\u{1900}'..='\u{191e}',
+    '\u{1950}'..='\u{196d}',
+    '\u{1970}'..='\u{1974}',
+    '\u{1980}'..='\u{19ab}',
+    '\u{19b0}'..='\u{19c9}',
+    '\u{1a00}'..='\u{1a16}',
+    '\u{1a20}'..='\u{1a54}',
+    '\u{1aa7}'..='\u{1aa7}',
+    '\u{1b05}'..='\u{1b33}',
+    '\u{1b45}'..='\u{1b4c}',
+    '\u{1b83}'..='\u{1ba0}',
+    '\u{1bae}'..='\u{1baf}',
+    '\u{1bba}'..='\u{1be5}',
+    '\u{1c00}'..='\u{1c23}',
+    '\u{1c4d}'..='\u{1c4f}',
+    '\u{1c5a}'..='\u{1c7d}',
+    '\u{1c80}'..='\u{1c8a}',
+    '\u{1c90}'..='\u{1cba}',
+    '\u{1cbd}'..='\u{1cbf}',
+    '\u{1ce9}'..='\u{1cec}',
+    '\u{1cee}'..='\u{1cf3}',
+    '\u{1cf5}'..='\u{1cf6}',
+    '\u{1cfa}'..='\u{1cfa}',
+    '\u{1d00}'..='\u{1dbf}',
+    '\u{1e00}'..='\u{1f15}',
+    '\u{1f18}'..='\u{1f1d}',
+    '\u{1f20}'..='\u{1f45}',
+    '\u{1f48}'..='\u{1f4d}',
+    '\u{1f50}'..='\u{1f57}',
+    '\u{1f59}'..='\u{1f59}',
+    '\u{1f5b}'..='\u{1f5b}',
+    '\u{1f5d}'..='\u{1f5d}',
+    '\u{1f5f}'..='\u{1f7d}',
+    '\u{1f80}'..='\u{1fb4}',
+    '\u{1fb6}'..='\u{1fbc}',
+    '\u{1fbe}'..='\u{1fbe}',
+    '\u{1fc2}'..='\u{1fc4}',
+    '\u{1fc6}'..='\u{1fcc}',
+    '\u{1fd0}'..='\u{1fd3}',
+    '\u{1fd6}'..='\u{1fdb}',
+    '\u{1fe0}'..='\u{1fec}',
+    '\u{1ff2}'..='\u{1ff4}',
+    '\u{1ff6}'..='\u{1ffc}',
+    '\u{2071}'..='\u{2071}',
+    '\u{207f}'..='\u{207f}',
+    '\u{2090}'..='\u{209c}',
+    '\u{2102}'..='\u{2102}',
+    '\u{2107}'..='\u{2107}',
+    '\u{210a}'..='\u{2113}',
+    '\u{2115}'..='\u{2115}',
+    '\u{2118}'..='\u{211d}',
+    '\u{2124}'..='\u{2124}',
+    '\u{2126}'..='\u{2126}',
+    '\u{2128}'..='\u{2128}',
+    '\u{212a}'..='\u{2139}',
+    '\u{213c}'..='\u{213f}',
+    '\u{2145}'..='\u{2149}',
+    '\u{214e}'..='\u{214e}',
+    '\u{2160}'..='\u{2188}',
+    '\u{2c00}'..='\u{2ce4}',
+    '\u{2ceb}'..='\u{2cee}',
+    '\u{2cf2}'..='\u{2cf3}',
+    '\u{2d00}'..='\u{2d25}',
+    '\u{2d27}'..='\u{2d27}',
+    '\u{2d2d}'..='\u{2d2d}',
+    '\u{2d30}'..='\u{2d67}',
+    '\u{2d6f}'..='\u{2d6f}',
+    '\u{2d80}'..='\u{2d96}',
+    '\u{2da0}'..='\u{2da6}',
+    '\u{2da8}'..='\u{2dae}',
+    '\u{2db0}'..='\u{2db6}',
+    '\u{2db8}'..='\u{2dbe}',
+    '\u{2dc0}'..='\u{2dc6}',
+    '\u{2dc8}'..='\u{2dce}',
+    '\u{2dd0}'..='\u{2dd6}',
+    '\u{2dd8}'..='\u{2dde}',
+    '\u{3005}'..='\u{3007}',
+    '\u{3021}'..='\u{3029}',
+    '\u{3031}'..='\u{3035}',
+    '\u{3038}'..='\u{303c}',
+    '\u{3041}'..='\u{3096}',
+    '\u{309d}'..='\u{309f}',
+    '\u{30a1}'..='\u{30fa}',
+    '\u{30fc}'..='\u{30ff}',
+    '\u{3105}'..='\u{312f}',
+    '\u{3131}'..='\u{318e}',
+    '\u{31a0}'..='\u{31bf}',
+    '\u{31f0}'..='\u{31ff}',
+    '\u{3400}'..='\u{4dbf}',
+    '\u{4e00}'..='\u{a48c}',
+    '\u{a4d0}'..='\u{a4fd}',
+    '\u{a500}'..='\u{a60c}',
+    '\u{a610}'..='\u{a61f}',
+    '\u{a62a}'..='\u{a62b}',
+    '\u{a640}'..='\u{a66e}',
+    '\u{a67f}'..='\u{a69d}',
+    '\u{a6a0}'..='\u{a6ef}',
+    '\u{a717}'..='\u{a71f}',
+    '\u{a722}'..='\u{a788}',
+    '\u{a78b}'..='\u{a7dc}',
+    '\u{a7f1}'..='\u{a801}',
+    '\u{a803}'..='\u{a805}',
+    '\u{a807}'..='\u{a80a}',
+    '\u{a80c}'..='\u{a822}',
+    '\u{a840}'..='\u{a873}',
+    '\u{a882}'..='\u{a8b3}',
+    '\u{a8f2}'..='\u{a8f7}',
+    '\u{a8fb}'..='\u{a8fb}',
+    '\u{a8fd}'..='\u{a8fe}',
+    '\u{a90a}'..='\u{a925}',
+    '\u{a930}'..='\u{a946}',
+    '\u{a960}'..='\u{a97c}',
+    '\u{a984}'..='\u{a9b2}',
+    '\u{a9cf}'..='\u{a9cf}',
+    '\u{a9e0}'..='\u{a9e4}',
+    '\u{a9e6}'..='\u{a9ef}',
+    '\u{a9fa}'..='\u{a9fe}',
+    '\u{aa00}'..='\u{aa28}',
+    '\u{aa40}'..='\u{aa42}',
+    '\u{aa44}'..='\u{aa4b}',
+    '\u{aa60}'..='\u{aa76}',
+    '\u{aa7a}'..='\u{aa7a}',
+    '\u{aa7e}'..='\u{aaaf}',
+    '\u{aab1}'..='\u{aab1}',
+    '\u{aab5}'..='\u{aab6}',
+    '\u{aab9}'..='\u{aabd}',
+    '\u{aac0}'..='\u{aac0}',
+    '\u{aac2}'..='\u{aac2}',
+    '\u{aadb}'..='\u{aadd}',
+    '\u{aae0}'..='\u{aaea}',
+    '\u{aaf2}'..='\u{aaf4}',
+    '\u{ab01}'..='\u{ab06}',
+    '\u{ab09}'..='\u{ab0e}',
+    '\u{ab11}'..='\u{ab16}',
+    '\u{ab20}'..='\u{ab26}',
+    '\u{ab28}'..='\u{ab2e}',
+    '\u{ab30}'..='\u{ab5a}',
+    '\u{ab5c}'..='\u{ab69}',
+    '\u{ab70}'..='\u{abe2}',
+    '\u{ac00}'..='\u{d7a3}',
+    '\u{d7b0}'..='\u{d7c6}',
+    '\u{d7cb}'..='\u{d7fb}',
+    '\u{f900}'..='\u{fa6d}',
+    '\u{fa70}'..='\u{fad9}',
+    '\u{fb00}'..='\u{fb06}',
+    '\u{fb13}'..='\u{fb17}',
+    '\u{fb1d}'..='\u{fb1d}',
+    '\u{fb1f}'..='\u{fb28}',
+    '\u{fb2a}'..='\u{fb36}',
+    '\u{fb38}'..='\u{fb3c}',
+    '\u{fb3e}'..='\u{fb3e}',
+    '\u{fb40}'..='\u{fb41}',
+    '\u{fb43}'..='\u{fb44}',
+    '\u{fb46}'..='\u{fbb1}',
+    '\u{fbd3}'..='\u{fc5d}',
+    '\u{fc64}'..='\u{fd3d}',
+    '\u{fd50}'..='\u{fd8f}',
+    '\u{fd92}'..='\u{fdc7}',
+    '\u{fdf0}'..='\u{fdf9}',
+    '\u{fe71}'..='\u{fe71}',
+    '\u{fe73}'..='\u{fe73}',
+    '\u{fe77}'..='\u{fe77}',
+    '\u{fe79}'..='\u{fe79}',
+    '\u{fe7b}'..='\u{fe7b}',
+    '\u{fe7d}'..='\u{fe7d}',
+    '\u{fe7f}'..='\u{fefc}',
+    '\u{ff21}'..='\u{ff3a}',
+    '\u{ff41}'..='\u{ff5a}',
+    '\u{ff66}'..='\u{ff9d}',
+    '\u{ffa0}'..='\u{ffbe}',
+    '\u{ffc2}'..='\u{ffc7}',
+    '\u{ffca}'..='\u{ffcf}',
+    '\u{ffd2}'..='\u{ffd7}',
+    '\u{ffda}'..='\u{ffdc}',
+    '\u{10000}'..='\u{1000b}',
+    '\u{1000d}'..='\u{10026}',
+    '\u{10028}'..='\u{1003a}',
+    '\u{1003c}'..='\u{1003d}',
+    '\u{1003f}'..='\u{1004d}',
+    '\u{10050}'..='\u{1005d}',
+    '\u{10080}'..='\u{100fa}',
+    '\u{10140}'..='\u{10174}',
+    '\u{10280}'..='\u{1029c}',
+    '\u{102a0}'..='\u{102d0}',
+    '\u{10300}'..='\u{1031f}',
+    '\u{1032d}'..='\u{1034a}',
+    '\u{10350}'..='\u{10375}',
+    '\u{10380}'..='\u{1039d}',
+    '\u{103a0}'..='\u{103c3}',
+    '\u{103c8}'..='\u{103cf}',
+    '\u{103d1}'..='\u{103d5}',
+    '\u{10400}'..='\u{1049d}',
+    '\u{104b0}'..='\u{104d3}',
+    '\u{104d8}'..='\u{104fb}',
+    '\u{10500}'..='\u{10527}',
+    '\u{10530}'..='\u{10563}',
+    '\u{10570}'..='\u{1057a}',
+    '\u{1057c}'..='\u{1058a}',
+    '\u{1058c}'..='\u{10592}',
+    '\u{10594}'..='\u{10595}',
+    '\u{10597}'..='\u{105a1}',
+    '\u{105a3}'..='\u{105b1}',
+    '\u{105b3}'..='\u{105b9}',
+    '\u{105bb}'..='\u{105bc}',
+    '\u{105c0}'..='\u{105f3}',
+    '\u{10600}'..='\u{10736}',
+    '\u{10740}'..='\u{10755}',
+    '\u{10760}'..='\u{10767}',
+    '\u{10780}'..='\u{10785}',
+    '\u{10787}'..='\u{107b0}',
+    '\u{107b2}'..='\u{107ba}',
+    '\u{10800}'..='\u{10805}',
+    '\u{10808}'..='\u{10808}',
+    '\u{1080a}'..='\u{10835}',
+    '\u{10837}'..='\u{10838}',
+    '\u{1083c}'..='\u{1083c}',
+    '\u{1083f}'..='\u{10855}',
+    '\u{10860}'..='\u{10876}',
+    '\u{10880}'..='\u{1089e}',
+    '\u{108e0}'..='\u{108f2}',
+    '\u{108f4}'..='\u{108f5}',
+    '\u{10900}'..='\u{10915}',
+    '\u{10920}'..='\u{10939}',
+    '\u{10940}'..='\u{10959}',
+    '\u{10980}'..='\u{109b7}',
+    '\u{109be}'..='\u{109bf}',
+    '\u{10a00}'..='\u{10a00}',
+    '\u{10a10}'..='\u{10a13}',
+    '\u{10a15}'..='\u{10a17}',
+    '\u{10a19}'..='\u{10a35}',
+    '\u{10a60}'..='\u{10a7c}',
+    '\u{10a80}'..='\u{10a9c}',
+    '\u{10ac0}'..='\u{10ac7}',
+    '\u{10ac9}'..='\u{10ae4}',
+    '\u{10b00}'..='\u{10b35}',
+    '\u{10b40}'..='\u{10b55}',
+    '\u{10b60}'..='\u{10b72}',
+    '\u{10b80}'..='\u{10b91}',
+    '\u{10c00}'..='\u{10c48}',
+    '\u{10c80}'..='\u{10cb2}',
+    '\u{10cc0}'..='\u{10cf2}',
+    '\u{10d00}'..='\u{10d23}',
+    '\u{10d4a}'..='\u{10d65}',
+    '\u{10d6f}'..='\u{10d85}',
+    '\u{10e80}'..='\u{10ea9}',
+    '\u{10eb0}'..='\u{10eb1}',
+    '\u{10ec2}'..='\u{10ec7}',
+    '\u{10f00}'..='\u{10f1c}',
+    '\u{10f27}'..='\u{10f27}',
+    '\u{10f30}'..='\u{10f45}',
+    '\u{10f70}'..='\u{10f81}',
+    '\u{10fb0}'..='\u{10fc4}',
+    '\u{10fe0}'..='\u{10ff6}',
+    '\u{11003}'..='\u{11037}',
+    '\u{11071}'..='\u{11072}',
+    '\u{11075}'..='\u{11075}',
+    '\u{11083}'..='\u{110af}',
+    '\u{110d0}'..='\u{110e8}',
+    '\u{11103}'..='\u{11126}',
+    '\u{11144}'..='\u{11144}',
+    '\u{11147}'..='\u{11147}',
+    '\u{11150}'..='\u{11172}',
+    '\u{11176}'..='\u{11176}',
+    '\u{11183}'..='\u{111b2}',
+    '\u{111c1}'..='\u{111c4}',
+    '\u{111da}'..='\u{111da}',
+    '\u{111dc}'..='\u{111dc}',
+    '\u{11200}'..='\u{11211}',
+    '\u{11213}'..='\u{1122b}',
+    '\u{1123f}'..='\u{11240}',
+    '\u{11280}'..='\u{11286}',
+    '\u{11288}'..='\u{11288}',
+    '\u{1128a}'..='\u{1128d}',
+    '\u{1128f}'..='\u{1129d}',
+    '\u{1129f}'..='\u{112a8}',
+    '\u{112b0}'..='\u{112de}',
+    '\u{11305}'..='\u{1130c}',
+    '\u{1130f}'..='\u{11310}',
+    '\u{11313}'..='\u{11328}',
+    '\u{1132a}'..='\u{11330}',
+    '\u{11332}'..='\u{11333}',
+    '\u{11335}'..='\u{11339}',
+    '\u{1133d}'..='\u{1133d}',
+    '\u{11350}'..='\u{11350}',
+    '\u{1135d}'..='\u{11361}',
+    '\u{11380}'..='\u{11389}',
+    '\u{1138b}'..='\u{1138b}',
+    '\u{1138e}'..='\u{1138e}',
+    '\u{11390}'..='\u{113b5}',
+    '\u{113b7}'..='\u{113b7}',
+    '\u{113d1}'..='\u{113d1}',
+    '\u{113d3}'..='\u{113d3}',
+    '\u{11400}'..='\u{11434}',
+    '\u{11447}'..='\u{1144a}',
+    '\u{1145f}'..='\u{11461}',
+    '\u{11480}'..='\u{114af}',
+    '\u{114c4}'..='\u{114c5}',
+    '\u{114c7}'..='\u{114c7}',
+    '\u{11580}'..='\u{115ae}',
+    '\u{115d8}'..='\u{115db}',
+    '\u{11600}'..='\u{1162f}',
+    '\u{11644}'..='\u{11644}',
+    '\u{11680}'..='\u{116aa}',
+    '\u{116b8}'..='\u{116b8}',
+    '\u{11700}'..='\u{1171a}',
+    '\u{11740}'..='\u{11746}',
+    '\u{11800}'..='\u{1182b}',
+    '\u{118a0}'..='\u{118df}',
+    '\u{118ff}'..='\u{11906}',
+    '\u{11909}'..='\u{11909}',
+    '\u{1190c}'..='\u{11913}',
+    '\u{11915}'..='\u{11916}',
+    '\u{11918}'..='\u{1192f}',
+    '\u{1193f}'..='\u{1193f}',
+    '\u{11941}'..='\u{11941}',
+    '\u{119a0}'..='\u{119a7}',
+    '\u{119aa}'..='\u{119d0}',
+    '\u{119e1}'..='\u{119e1}',
+    '\u{119e3}'..='\u{119e3}',
+    '\u{11a00}'..='\u{11a00}',
+    '\u{11a0b}'..='\u{11a32}',
+    '\u{11a3a}'..='\u{11a3a}',
+    '\u{11a50}'..='\u{11a50}',
+    '\u{11a5c}'..='\u{11a89}',
+    '\u{11a9d}'..='\u{11a9d}',
+    '\u{11ab0}'..='\u{11af8}',
+    '\u{11bc0}'..='\u{11be0}',
+    '\u{11c00}'..='\u{11c08}',
+    '\u{11c0a}'..='\u{11c2e}',
+    '\u{11c40}'..='\u{11c40}',
+    '\u{11c72}'..='\u{11c8f}',
+    '\u{11d00}'..='\u{11d06}',
+    '\u{11d08}'..='\u{11d09}',
+    '\u{11d0b}'..='\u{11d30}',
+    '\u{11d46}'..='\u{11d46}',
+    '\u{11d60}'..='\u{11d65}',
+    '\u{11d67}'..='\u{11d68}',
+    '\u{11d6a}'..='\u{11d89}',
+    '\u{11d98}'..='\u{11d98}',
+    '\u{11db0}'..='\u{11ddb}',
+    '\u{11ee0}'..='\u{11ef2}',
+    '\u{11f02}'..='\u{11f02}',
+    '\u{11f04}'..='\u{11f10}',
+    '\u{11f12}'..='\u{11f33}',
+    '\u{11fb0}'..='\u{11fb0}',
+    '\u{12000}'..='\u{12399}',
+    '\u{12400}'..='\u{1246e}',
+    '\u{12480}'..='\u{12543}',
+    '\u{12f90}'..='\u{12ff0}',
+    '\u{13000}'..='\u{1342f}',
+    '\u{13441}'..='\u{13446}',
+    '\u{13460}'..='\u{143fa}',
+    '\u{14400}'..='\u{14646}',
+    '\u{16100}'..='\u{1611d}',
+    '\u{16800}'..='\u{16a38}',
+    '\u{16a40}'..='\u{16a5e}',
+    '\u{16a70}'..='\u{16abe}',
+    '\u{16ad0}'..='\u{16aed}',
+    '\u{16b00}'..='\u{16b2f}',
+    '\u{16b40}'..='\u{16b43}',
+    '\u{16b63}'..='\u{16b77}',
+    '\u{16b7d}'..='\u{16b8f}',
+    '\u{16d40}'..='\u{16d6c}',
+    '\u{16e40}'..='\u{16e7f}',
+    '\u{16ea0}'..='\u{16eb8}',
+    '\u{16ebb}'..='\u{16ed3}',
+    '\u{16f00}'..='\u{16f4a}',
+    '\u{16f50}'..='\u{16f50}',
+    '\u{16f93}'..='\u{16f9f}',
+    '\u{16fe0}'..='\u{16fe1}',
+    '\u{16fe3}'..='\u{16fe3}',
+    '\u{16ff2}'..='\u{16ff6}',
+    '\u{17000}'..='\u{18cd5}',
+    '\u{18cff}'..='\u{18d1e}',
+    '\u{18d80}'..='\u{18df2}',
+    '\u{1aff0}'..='\u{1aff3}',
+    '\u{1aff5}'..='\u{1affb}',
+    '\u{1affd}'..='\u{1affe}',
+    '\u{1b000}'..='\u{1b122}',
+    '\u{1b132}'..='\u{1b132}',
+    '\u{1b150}'..='\u{1b152}',
+    '\u{1b155}'..='\u{1b155}',
+    '\u{1b164}'..='\u{1b167}',
+    '\u{1b170}'..='\u{1b2fb}',
+    '\u{1bc00}'..='\u{1bc6a}',
+    '\u{1bc70}'..='\u{1bc7c}',
+    '\u{1bc80}'..='\u{1bc88}',
+    '\u{1bc90}'..='\u{1bc99}',
+    '\u{1d400}'..='\u{1d454}',
+    '\u{1d456}'..='\u{1d49c}',
+    '\u{1d49e}'..='\u{1d49f}',
+    '\u{1d4a2}'..='\u{1d4a2}',
+    '\u{1d4a5}'..='\u{1d4a6}',
+    '\u{1d4a9}'..='\u{1d4ac}',
+    '\u{1d4ae}'..='\u{1d4b9}',
+    '\u{1d4bb}'..='\u{1d4bb}',
+    '\u{1d4bd}'..='\u{1d4c3}',
+    '\u{1d4c5}'..='\u{1d505}',
+    '\u{1d507}'..='\u{1d50a}',
+    '\u{1d50d}'..='\u{1d514}',
+    '\u{1d516}'..='\u{1d51c}',
+    '\u{1d51e}'..='\u{1d539}',
+    '\u{1d53b}'..='\u{1d53e}',
+    '\u{1d540}'..='\u{1d544}',
+    '\u{1d546}'..='\u{1d546}',
+    '\u{1d54a}'..='\u{1d550}',
+    '\u{1d552}'..='\u{1d6a5}',
+    '\u{1d6a8}'..='\u{1d6c0}',
+    '\u{1d6c2}'..='\u{1d6da}',
+    '\u{1d6dc}'..='\u{1d6fa}',
+    '\u{1d6fc}'..='\u{1d714}',
+    '\u{1d716}'..='\u{1d734}',
+    '\u{1d736}'..='\u{1d74e}',
+    '\u{1d750}'..='\u{1d76e}',
+    '\u{1d770}'..='\u{1d788}',
+    '\u{1d78a}'..='\u{1d7a8}',
+    '\u{1d7aa}'..='\u{1d7c2}',
+    '\u{1d7c4}'..='\u{1d7cb}',
+    '\u{1df00}'..='\u{1df1e}',
+    '\u{1df25}'..='\u{1df2a}',
+    '\u{1e030}'..='\u{1e06d}',
+    '\u{1e100}'..='\u{1e12c}',
+    '\u{1e137}'..='\u{1e13d}',
+    '\u{1e14e}'..='\u{1e14e}',
+    '\u{1e290}'..='\u{1e2ad}',
+    '\u{1e2c0}'..='\u{1e2eb}',
+    '\u{1e4d0}'..='\u{1e4eb}',
+    '\u{1e5d0}'..='\u{1e5ed}',
+    '\u{1e5f0}'..='\u{1e5f0}',
+    '\u{1e6c0}'..='\u{1e6de}',
+    '\u{1e6e0}'..='\u{1e6e2}',
+    '\u{1e6e4}'..='\u{1e6e5}',
+    '\u{1e6e7}'..='\u{1e6ed}',
+    '\u{1e6f0}'..='\u{1e6f4}',
+    '\u{1e6fe}'..='\u{1e6ff}',
+    '\u{1e7e0}'..='\u{1e7e6}',
+    '\u{1e7e8}'..='\u{1e7eb}',
+    '\u{1e7ed}'..='\u{1e7ee}',
+    '\u{1e7f0}'..='\u{1e7fe}',
+    '\u{1e800}'..='\u{1e8c4}',
+    '\u{1e900}'..='\u{1e943}',
+    '\u{1e94b}'..='\u{1e94b}',
+    '\u{1ee00}'..='\u{1ee03}',
+    '\u{1ee05}'..='\u{1ee1f}',
+    '\u{1ee21}'..='\u{1ee22}',
+    '\u{1ee24}'..='\u{1ee24}',
+    '\u{1ee27}'..='\u{1ee27}',
+    '\u{1ee29}'..='\u{1ee32}',
+    '\u{1ee34}'..='\u{1ee37}',
+    '\u{1ee39}'..='\u{1ee39}',
+    '\u{1ee3b}'..='\u{1ee3b}',
+    '\u{1ee42}'..='\u{1ee42}',
+    '\u{1ee47}'..='\u{1ee47}',
+    '\u{1ee49}'..='\u{1ee49}',
+    '\u{1ee4b}'..='\u{1ee4b}',
+    '\u{1ee4d}'..='\u{1ee4f}',
+    '\u{1ee51}'..='\u{1ee52}',
+    '\u{1ee54}'..='\u{1ee54}',
+    '\u{1ee57}'..='\u{1ee57}',
+    '\u{1ee59}'..='\u{1ee59}',
+    '\u{1ee5b}'..='\u{1ee5b}',
+    '\u{1ee5d}'..='\u{1ee5d}',
+    '\u{1ee5f}'..='\u{1ee5f}',
+    '\u{1ee61}'..='\u{1ee62}',
+    '\u{1ee64}'..='\u{1ee64}',
+    '\u{1ee67}'..='\u{1ee6a}',
+    '\u{1ee6c}'..='\u{1ee72}',
+    '\u{1ee74}'..='\u{1ee77}',
+    '\u{1ee79}'..='\u{1ee7c}',
+    '\u{1ee7e}'..='\u{1ee7e}',
+    '\u{1ee80}'..='\u{1ee89}',
+    '\u{1ee8b}'..='\u{1ee9b}',
+    '\u{1eea1}'..='\u{1eea3}',
+    '\u{1eea5}'..='\u{1eea9}',
+    '\u{1eeab}'..='\u{1eebb}',
+    '\u{20000}'..='\u{2a6df}',
+    '\u{2a700}'..='\u{2b81d}',
+    '\u{2b820}'..='\u{2cead}',
+    '\u{2ceb0}'..='\u{2ebe0}',
+    '\u{2ebf0}'..='\u{2ee5d}',
+    '\u{2f800}'..='\u{2fa1d}',
+    '\u{30000}'..='\u{3134a}',
+    '\u{31350}'..='\u{33479}',
+];
+
+pub(crate) static XID_CONTINUE: &[CharClassRange] = &[
+    '\u{30}'..='\u{39}',
+    '\u{41}'..='\u{5a}',
+    '\u{5f}'..='\u{5f}',
+    '\u{61}'..='\u{7a}',
+    '\u{aa}'..='\u{aa}',
+    '\u{b5}'..='\u{b5}',
+    '\u{b7}'..='\u{b7}',
+    '\u{ba}'..='\u{ba}',
+    '\u{c0}'..='\u{d6}',
+    '\u{d8}'..='\u{f6}',
+    '\u{f8}'..='\u{2c1}',
+    '\u{2c6}'..='\u{2d1}',
+    '\u{2e0}'..='\u{2e4}',
+    '\u{2ec}'..='\u{2ec}',
+    '\u{2ee}'..='\u{2ee}',
+    '\u{300}'..='\u{374}',
+    '\u{376}'..='\u{377}',
+    '\u{37b}'..='\u{37d}',
+    '\u{37f}'..='\u{37f}',
+    '\u{386}'..='\u{38a}',
+    '\u{38c}'..='\u{38c}',
+    '\u{38e}'..='\u{3a1}',
+    '\u{3a3}'..='\u{3f5}',
+    '\u{3f7}'..='\u{481}',
+    '\u{483}'..='\u{487}',
+    '\u{48a}'..='\u{52f}',
+    '\u{531}'..='\u{556}',
+    '\u{559}'..='\u{559}',
+    '\u{560}'..='\u{588}',
+    '\u{591}'..='\u{5bd}',
+    '\u{5bf}'..='\u{5bf}',
+    '\u{5c1}'..='\u{5c2}',
+    '\u{5c4}'..='\u{5c5}',
+    '\u{5c7}'..='\u{5c7}',
+    '\u{5d0}'..='\u{5ea}',
+    '\u{5ef}'..='\u{5f2}',
+    '\u{610}'..='\u{61a}',
+    '\u{620}'..='\u{669}',
+    '\u{66e}'..='\u{6d3}',
+    '\u{6d5}'..='\u{6dc}',
+    '\u{6df}'..='\u{6e8}',
+    '\u{6ea}'..='\u{6fc}',
+    '\u{6ff}'..='\u{6ff}',
+    '\u{710}'..='\u{74a}',
+    '\u{74d}'..='\u{7b1}',
+    '\u{7c0}'..='\u{7f5}',
+    '\u{7fa}'..='\u{7fa}',
+    '\u{7fd}'..='\u{7fd}',
+    '\u{800}'..='\u{82d}',
+    '\u{840}'..='\u{85b}',
+    '\u{860}'..='\u{86a}',
+    '\u{870}'..='\u{887}',
+    '\u{889}'..='\u{88f}',
+    '\u{897}'..='\u{8e1}',
+    '\u{8e3}'..='\u{963}',
+    '\u{966}'..='\u{96f}',
+    '\u{971}'..='\u{983}',
+    '\u{985}'..='\u{98c}',
+    '\u{98f}'..='\u{990}',
+    '\u{993}'..='\u{9a8}',
+    '\u{9aa}'..='\u{9b0}',
+    '\u{9b2}'..='\u{9b2}',
+    '\u{9b6}'..='\u{9b9}',
+    '\u{9bc}'..='\u{9c4}',
+    '\u{9c7}'..='\u{9c8}',
+    '\u{9cb}'..='\u{9ce}',
+    '\u{9d7}'..='\u{9d7}',
+    '\u{9dc}'..='\u{9dd}',
+    '\u{9df}'..='\u{9e3}',
+    '\u{9e6}'..='\u{9f1}',
+    '\u{9fc}'..='\u{9fc}',
+    '\u{9fe}'..='\u{9fe}',
+    '\u{a01}'..='\u{a03}',
+    '\u{a05}'..='\u{a0a}',
+    '\u{a0f}'..='\u{a10}',
+    '\u{a13}'..='\u{a28}',
+    '\u{a2a}'..='\u{a30}',
+    '\u{a32}'..='\u{a33}',
+    '\u{a35}'..='\u{a36}',
+    '\u{a38}'..='\u{a39}',
+    '\u{a3c}'..='\u{a3c}',
+    '\u{a3e}'..='\u{a42}',
+    '\u{a47}'..='\u{a48}',
+    '\u{a4b}'..='\u{a4d}',
+    '\u{a51}'..='\u{a51}',
+    '\u{a59}'..='\u{a5c}',
+    '\u{a5e}'..='\u{a5e}',
+    '\u{a66}'..='\u{a75}',
+    '\u{a81}'..='\u{a83}',
+    '\u{a85}'..='\u{a8d}',
+    '\u{a8f}'..='\u{a91}',
+    '\u{a93}'..='\u{aa8}',
+    '\u{aaa}'..='\u{ab0}',
+    '\u{ab2}'..='\u{ab3}',
+    '\u{ab5}'..='\u{ab9}',
+    '\u{abc}'..='\u{ac5}',
+    '\u{ac7}'..='\u{ac9}',
+    '\u{acb}'..='\u{acd}',
+    '\u{ad0}'..='\u{ad0}',
+    '\u{ae0}'..='\u{ae3}',
+    '\u{ae6}'..='\u{aef}',
+    '\u{af9}'..='\u{aff}',
+    '\u{b01}'..='\u{b03}',
+    '\u{b05}'..='\u{b0c}',
+    '\u{b0f}'..='\u{b10}',
+    '\u{b13}'..='\u{b28}',
+    '\u{b2a}'..='\u{b30}',
+    '\u{b32}'..='\u{b33}',
+    '\u{b35}'..='\u{b39}',
+    '\u{b3c}'..='\u{b44}',
+    '\u{b47}'..='\u{b48}',
+    '\u{b4b}'..='\u{b4d}',
+    '\u{b55}'..='\u{b57}',
+    '\u{b5c}'..='\u{b5d}',
+    '\u{b5f}'..='\u{b63}',
+    '\u{b66}'..='\u{b6f}',
+    '\u{b71}'..='\u{b71}',
+    '\u{b82}'..='\u{b83}',
+    '\u{b85}'..='\u{b8a}',
+    '\u{b8e}'..='\u{b90}',
+    '\u{b92}'..='\u{b95}',
+    '\u{b99}'..='\u{b9a}',
+    '\u{b9c}'..='\u{b9c}',
+    '\u{b9e}'..='\u{b9f}',
+    '\u{ba3}'..='\u{ba4}',
+    '\u{ba8}'..='\u{baa}',
+    '\u{bae}'..='\u{bb9}',
+    '\u{bbe}'..='\u{bc2}',
+    '\u{bc6}'..='\u{bc8}',
+    '\u{bca}'..='\u{bcd}',
+    '\u{bd0}'..='\u{bd0}',
+    '\u{bd7}'..='\u{bd7}',
+    '\u{be6}'..='\u{bef}',
+    '\u{c00}'..='\u{c0c}',
+    '\u{c0e}'..='\u{c10}',
+    '\u{c12}'..='\u{c28}',
+    '\u{c2a}'..='\u{c39}',
+    '\u{c3c}'..='\u{c44}',
+    '\u{c46}'..='\u{c48}',
+    '\u{c4a}'..='\u{c4d}',
+    '\u{c55}'..='\u{c56}',
+    '\u{c58}'..='\u{c5a}',
+    '\u{c5c}'..='\u{c5d}',
+    '\u{c60}'..='\u{c63}',
+    '\u{c66}'..='\u{c6f}',
+    '\u{c80}'..='\u{c83}',
+    '\u{c85}'..='\u{c8c}',
+    '\u{c8e}'..='\u{c90}',
+    '\u{c92}'..='\u{ca8}',
+    '\u{caa}'..='\u{cb3}',
+    '\u{cb5}'..='\u{cb9}',
+    '\u{cbc}'..='\u{cc4}',
+    '\u{cc6}'..='\u{cc8}',
+    '\u{cca}'..='\u{ccd}',
+    '\u{cd5}'..='\u{cd6}',
+    '\u{cdc}'..='\u{cde}',
+    '\u{ce0}'..='\u{ce3}',
+    '\u{ce6}'..='\u{cef}',
+    '\u{cf1}'..='\u{cf3}',
+    '\u{d00}'..='\u{d0c}',
+    '\u{d0e}'..='\u{d10}',
+    '\u{d12}'..='\u{d44}',
+    '\u{d46}'..='\u{d48}',
+    '\u{d4a}'..='\u{d4e}',
+    '\u{d54}'..='\u{d57}',
+    '\u{d5f}'..='\u{d63}',
+    '\u{d66}'..='\u{d6f}',
+    '\u{d7a}'..='\u{d7f}',
+    '\u{d81}'..='\u{d83}',
+    '\u{d85}'..='\u{d96}',
+    '\u{d9a}'..='\u{db1}',
+    '\u{db3}'..='\u{dbb}',
+    '\u{dbd}'..='\u{dbd}',
+    '\u{dc0}'..='\u{dc6}',
+    '\u{dca}'..='\u{dca}',
+    '\u{dcf}'..='\u{dd4}',
+    '\u{dd6}'..='\u{dd6}',
+    '\u{dd8}'..='\u{ddf}',
+    '\u{de6}'..='\u{def}',
+    '\u{df2}'..='\u{df3}',
+    '\u{e01}'..='\u{e3a}',
+    '\u{e40}'..='\u{e4e}',
+    '\u{e50}'..='\u{e59}',
+    '\u{e81}'..='\u{e82}',
+    '\u{e84}'..='\u{e84}',
+    '\u{e86}'..='\u{e8a}',
+    '\u{e8c}'..='\u{ea3}',
+    '\u{ea5}'..='\u{ea5}',
+    '\u{ea7}'..='\u{ebd}',
+    '\u{ec0}'..='\u{ec4}',
+    '\u{ec6}'..='\u{ec6}',
+    '\u{ec8}'..='\u{ece}',
+    '\u{ed0}'..='\u{ed9}',
+    '\u{edc}'..='\u{edf}',
+    '\u{f00}'..='\u{f00}',
+    '\u{f18}'..='\u{f19}',
+    '\u{f20}'..='\u{f29}',
+    '\u{f35}'..='\u{f35}',
+    '\u{f37}'..='\u{f37}',
+    '\u{f39}'..='\u{f39}',
+    '\u{f3e}'..='\u{f47}',
+    '\u{f49}'..='\u{f6c}',
+    '\u{f71}'..='\u{f84}',
+    '\u{f86}'..='\u{f97}',
+    '\u{f99}'..='\u{fbc}',
+    '\u{fc6}'..='\u{fc6}',
+    '\u{1000}'..='\u{1049}',
+    '\u{1050}'..='\u{109d}',
+    '\u{10a0}'..='\u{10c5}',
+    '\u{10c7}'..='\u{10c7}',
+    '\u{10cd}'..='\u{10cd}',
+    '\u{10d0}'..='\u{10fa}',
+    '\u{10fc}'..='\u{1248}',
+    '\u{124a}'..='\u{124d}',
+    '\u{1250}'..='\u{1256}',
+    '\u{1258}'..='\u{1258}',
+    '\u{125a}'..='\u{125d}',
+    '\u{1260}'..='\u{1288}',
+    '\u{128a}'..='\u{128d}',
+    '\u{1290}'..='\u{12b0}',
+    '\u{12b2}'..='\u{12b5}',
+    '\u{12b8}'..='\u{12be}',
+    '\u{12c0}'..='\u{12c0}',
+    '\u{12c2}'..='\u{12c5}',
+    '\u{12c8}'..='\u{12d6}',
+    '\u{12d8}'..='\u{1310}',
+    '\u{1312}'..='\u{1315}',
+    '\u{1318}'..='\u{135a}',
+    '\u{135d}'..='\u{135f}',
+    '\u{1369}'..='\u{1371}',
+    '\u{1380}'..='\u{138f}',
+    '\u{13a0}'..='\u{13f5}',
+    '\u{13f8}'..='\u{13fd}',
+    '\u{1401}'..='\u{166c}',
+    '\u{166f}'..='\u{167f}',
+    '\u{1681}'..='\u{169a}',
+    '\u{16a0}'..='\u{16ea}',
+    '\u{16ee}'..='\u{16f8}',
+    '\u{1700}'..='\u{1715}',
+    '\u{171f}'..='\u{1734}',
+    '\u{1740}'..='\u{1753}',
+    '\u{1760}'..='\u{176c}',
+    '\u{176e}'..='\u{1770}',
+    '\u{1772}'..='\u{1773}',
+    '\u{1780}'..='\u{17d3}',
+    '\u{17d7}'..='\u{17d7}',
+    '\u{17dc}'..='\u{17dd}',
+    '\u{17e0}'..='\u{17e9}',
+    '\u{180b}'..='\u{180d}',
+    '\u{180f}'..='\u{1819}',
+    '\u{1820}'..='\u{1878}',
+    '\u{1880}'..='\u{18aa}',
+    '\u{18b0}'..='\u{18f5}',
+    '\u{1900}'..='\u{191e}',
+    '\u{1920}'..='\u{192b}',
+    '\u{1930}'..='\u{193b}',
+    '\u{1946}'..='\u{196d}',
+    '\u{1970}'..='\u{1974}',
+    '\u{1980}'..='\u{19ab}',
+    '\u{19b0}'..='\u{19c9}',
+    '\u{19d0}'..='\u{19da}',
+    '\u{1a00}'..='\u{1a1b}',
+    '\u{1a20}'..='\u{1a5e}',
+    '\u{1a60}'..='\u{1a7c}',
+    '\u{1a7f}'..='\u{1a89}',
+    '\u{1a90}'..='\u{1a99}',
+    '\u{1aa7}'..='\u{1aa7}',
+    '\u{1ab0}'..='\u{1abd}',
+    '\u{1abf}'..='\u{1add}',
+    '\u{1ae0}'..='\u{1aeb}',
+    '\u{1b00}'..='\u{1b4c}',
+    '\u{1b50}'..='\u{1b59}',
+    '\u{1b6b}'..='\u{1b73}',
+    '\u{1b80}'..='\u{1bf3}',
+    '\u{1c00}'..='\u{1c37}',
+    '\u{1c40}'..='\u{1c49}',
+    '\u{1c4d}'..='\u{1c7d}',
+    '\u{1c80}'..='\u{1c8a}',
+    '\u{1c90}'..='\u{1cba}',
+    '\u{1cbd}'..='\u{1cbf}',
+    '\u{1cd0}'..='\u{1cd2}',
+    '\u{1cd4}'..='\u{1cfa}',
+    '\u{1d00}'..='\u{1f15}',
+    '\u{1f18}'..='\u{1f1d}',
+    '\u{1f20}'..='\u{1f45}',
+    '\u{1f48}'..='\u{1f4d}',
+    '\u{1f50}'..='\u{1f57}',
+    '\u{1f59}'..='\u{1f59}',
+    '\u{1f5b}'..='\u{1f5b}',
+    '\u{1f5d}'..='\u{1f5d}',
+    '\u{1f5f}'..='\u{1f7d}',
+    '\u{1f80}'..='\u{1fb4}',
+    '\u{1fb6}'..='\u{1fbc}',
+    '\u{1fbe}'..='\u{1fbe}',
+    '\u{1fc2}'..='\u{1fc4}',
+    '\u{1fc6}'..='\u{1fcc}',
+    '\u{1fd0}'..='\u{1fd3}',
+    '\u{1fd6}'..='\u{1fdb}',
+    '\u{1fe0}'..='\u{1fec}',
+    '\u{1ff2}'..='\u{1ff4}',
+    '\u{1ff6}'..='\u{1ffc}',
+    '\u{200c}'..='\u{200d}',
+    '\u{203f}'..='\u{2040}',
+    '\u{2054}'..='\u{2054}',
+    '\u{2071}'..='\u{2071}',
+    '\u{207f}'..='\u{207f}',
+    '\u{2090}'..='\u{209c}',
+    '\u{20d0}'..='\u{20dc}',
+    '\u{20e1}'..='\u{20e1}',
+    '\u{20e5}'..='\u{20f0}',
+    '\u{2102}'..='\u{2102}',
+    '\u{2107}'..='\u{2107}',
+    '\u{210a}'..='\u{2113}',
+    '\u{2115}'..='\u{2115}',
+    '\u{2118}'..='\u{211d}',
+    '\u{2124}'..='\u{2124}',
+    '\u{2126}'..='\u{2126}',
+    '\u{2128}'..='\u{2128}',
+    '\u{212a}'..='\u{2139}',
+    '\u{213c}'..='\u{213f}',
+    '\u{2145}'..='\u{2149}',
+    '\u{214e}'..='\u{214e}',
+    '\u{2160}'..='\u{2188}',
+    '\u{2c00}'..='\u{2ce4}',
+    '\u{2ceb}'..='\u{2cf3}',
+    '\u{2d00}'..='\u{2d25}',
+    '\u{2d27}'..='\u{2d27}',
+    '\u{2d2d}'..='\u{2d2d}',
+    '\u{2d30}'..='\u{2d67}',
+    '\u{2d6f}'..='\u{2d6f}',
+    '\u{2d7f}'..='\u{2d96}',
+    '\u{2da0}'..='\u{2da6}',
+    '\u{2da8}'..='\u{2dae}',
+    '\u{2db0}'..='\u{2db6}',
+    '\u{2db8}'..='\u{2dbe}',
+    '\u{2dc0}'..='\u{2dc6}',
+    '\u{2dc8}'..='\u{2dce}',
+    '\u{2dd0}'..='\u{2dd6}',
+    '\u{2dd8}'..='\u{2dde}',
+    '\u{2de0}'..='\u{2dff}',
+    '\u{3005}'..='\u{3007}',
+    '\u{3021}'..='\u{302f}',
+    '\u{3031}'..='\u{3035}',
+    '\u{3038}'..='\u{303c}',
+    '\u{3041}'..='\u{3096}',
+    '\u{3099}'..='\u{309a}',
+    '\u{309d}'..='\u{309f}',
+    '\u{30a1}'..='\u{30ff}',
+    '\u{3105}'..='\u{312f}',
+    '\u{3131}'..='\u{318e}',
+    '\u{31a0}'..='\u{31bf}',
+    '\u{31f0}'..='\u{31ff}',
+    '\u{3400}'..='\u{4dbf}',
+    '\u{4e00}'..='\u{a48c}',
+    '\u{a4d0}'..='\u{a4fd}',
+    '\u{a500}'..='\u{a60c}',
+    '\u{a610}'..='\u{a62b}',
+    '\u{a640}'..='\u{a66f}',
+    '\u{a674}'..='\u{a67d}',
+    '\u{a67f}'..='\u{a6f1}',
+    '\u{a717}'..='\u{a71f}',
+    '\u{a722}'..='\u{a788}',
+    '\u{a78b}'..='\u{a7dc}',
+    '\u{a7f1}'..='\u{a827}',
+    '\u{a82c}'..='\u{a82c}',
+    '\u{a840}'..='\u{a873}',
+    '\u{a880}'..='\u{a8c5}',
+    '\u{a8d0}'..='\u{a8d9}',
+    '\u{a8e0}'..='\u{a8f7}',
+    '\u{a8fb}'..='\u{a8fb}',
+    '\u{a8fd}'..='\u{a92d}',
+    '\u{a930}'..='\u{a953}',
+    '\u{a960}'..='\u{a97c}',
+    '\u{a980}'..='\u{a9c0}',
+    '\u{a9cf}'..='\u{a9d9}',
+    '\u{a9e0}'..='\u{a9fe}',
+    '\u{aa00}'..='\u{aa36}',
+    '\u{aa40}'..='\u{aa4d}',
+    '\u{aa50}'..='\u{aa59}',
+    '\u{aa60}'..='\u{aa76}',
+    '\u{aa7a}'..='\u{aac2}',
+    '\u{aadb}'..='\u{aadd}',
+    '\u{aae0}'..='\u{aaef}',
+    '\u{aaf2}'..='\u{aaf6}',
+    '\u{ab01}'..='\u{ab06}',
+    '\u{ab09}'..='\u{ab0e}',
+    '\u{ab11}'..='\u{ab16}',
+    '\u{ab20}'..='\u{ab26}',
+    '\u{ab28}'..='\u{ab2e}',
+    '\u{ab30}'..='\u{ab5a}',
+    '\u{ab5c}'..='\u{ab69}',
+    '\u{ab70}'..='\u{abea}',
+    '\u{abec}'..='\u{abed}',
+    '\u{abf0}'..='\u{abf9}',
+    '\u{ac00}'..='\u{d7a3}',
+    '\u{d7b0}'..='\u{d7c6}',
+    '\u{d7cb}'..='\u{d7fb}',
+    '\u{f900}'..='\u{fa6d}',
+    '\u{fa70}'..='\u{fad9}',
+    '\u{fb00}'..='\u{fb06}',
+    '\u{fb13}'..='\u{fb17}',
+    '\u{fb1d}'..='\u{fb28}',
+    '\u{fb2a}'..='\u{fb36}',
+    '\u{fb38}'..='\u{fb3c}',
+    '\u{fb3e}'..='\u{fb3e}',
+    '\u{fb40}'..='\u{fb41}',
+    '\u{fb43}'..='\u{fb44}',
+    '\u{fb46}'..='\u{fbb1}',
+    '\u{fbd3}'..='\u{fc5d}',
+    '\u{fc64}'..='\u{fd3d}',
+    '\u{fd50}'..='\u{fd8f}',
+    '\u{fd92}'..='\u{fdc7}',
+    '\u{fdf0}'..='\u{fdf9}',
+    '\u{fe00}'..='\u{fe0f}',
+    '\u{fe20}'..='\u{fe2f}',
+    '\u{fe33}'..='\u{fe34}',
+    '\u{fe4d}'..='\u{fe4f}',
+    '\u{fe71}'..='\u{fe71}',
+    '\u{fe73}'..='\u{fe73}',
+    '\u{fe77}'..='\u{fe77}',
+    '\u{fe79}'..='\u{fe79}',
+    '\u{fe7b}'..='\u{fe7b}',
+    '\u{fe7d}'..='\u{fe7d}',
+    '\u{fe7f}'..='\u{fefc}',
+    '\u{ff10}'..='\u{ff19}',
+    '\u{ff21}'..='\u{ff3a}',
+    '\u{ff3f}'..='\u{ff3f}',
+    '\u{ff41}'..='\u{ff5a}',
+    '\u{ff65}'..='\u{ffbe}',
+    '\u{ffc2}'..='\u{ffc7}',
+    '\u{ffca}'..='\u{ffcf}',
+    '\u{ffd2}'..='\u{ffd7}',
+    '\u{ffda}'..='\u{ffdc}',
+    '\u{10000}'..='\u{1000b}',
+    '\u{1000d}'..='\u{10026}',
+    '\u{10028}'..='\u{1003a}',
+    '\u{1003c}'..='\u{1003d}',
+    '\u{1003f}'..='\u{1004d}',
+    '\u{10050}'..='\u{1005d}',
+    '\u{10080}'..='\u{100fa}',
+    '\u{10140}'..='\u{10174}',
+    '\u{101fd}'..='\u{101fd}',
+    '\u{10280}'..='\u{1029c}',
+    '\u{102a0}'..='\u{102d0}',
+    '\u{102e0}'..='\u{102e0}',
+    '\u{10300}'..='\u{1031f}',
+    '\u{1032d}'..='\u{1034a}',
+    '\u{10350}'..='\u{1037a}',
+    '\u{10380}'..='\u{1039d}',
+    '\u{103a0}'..='\u{103c3}',
+    '\u{103c8}'..='\u{103cf}',
+    '\u{103d1}'..='\u{103d5}',
+    '\u{10400}'..='\u{1049d}',
+    '\u{104a0}'..='\u{104a9}',
+    '\u{104b0}'..='\u{104d3}',
+    '\u{104d8}'..='\u{104fb}',
+    '\u{10500}'..='\u{10527}',
+    '\u{10530}'..='\u{10563}',
+    '\u{10570}'..='\u{1057a}',
+    '\u{1057c}'..='\u{1058a}',
+    '\u{1058c}'..='\u{10592}',
+    '\u{10594}'..='\u{10595}',
+    '\u{10597}'..='\u{105a1}',
+    '\u{105a3}'..='\u{105b1}',
+    '\u{105b3}'..='\u{105b9}',
+    '\u{105bb}'..='\u{105bc}',
+    '\u{105c0}'..='\u{105f3}',
+    '\u{10600}'..='\u{10736}',
+    '\u{10740}'..='\u{10755}',
+    '\u{10760}'..='\u{10767}',
+    '\u{10780}'..='\u{10785}',
+    '\u{10787}'..='\u{107b0}',
+    '\u{107b2}'..='\u{107ba}',
+    '\u{10800}'..='\u{10805}',
+    '\u{10808}'..='\u{10808}',
+    '\u{1080a}'..='\u{10835}',
+    '\u{10837}'..='\u{10838}',
+    '\u{1083c}'..='\u{1083c}',
+    '\u{1083f}'..='\u{10855}',
+    '\u{10860}'..='\u{10876}',
+    '\u{10880}'..='\u{1089e}',
+    '\u{108e0}'..='\u{108f2}',
+    '\u{108f4}'..='\u{108f5}',
+    '\u{10900}'..='\u{10915}',
+    '\u{10920}'..='\u{10939}',
+    '\u{10940}'..='\u{10959}',
+    '\u{10980}'..='\u{109b7}',
+    '\u{109be}'..='\u{109bf}',
+    '\u{10a00}'..='\u{10a03}',
+    '\u{10a05}'..='\u{10a06}',
+    '\u{10a0c}'..='\u{10a13}',
+    '\u{10a15}'..='\u{10a17}',
+    '\u{10a19}'..='\u{10a35}',
+    '\u{10a38}'..='\u{10a3a}',
+    '\u{10a3f}'..='\u{10a3f}',
+    '\u{10a60}'..='\u{10a7c}',
+    '\u{10a80}'..='\u{10a9c}',
+    '\u{10ac0}'..='\u{10ac7}',
+    '\u{10ac9}'..='\u{10ae6}',
+    '\u{10b00}'..='\u{10b35}',
+    '\u{10b40}'..='\u{10b55}',
+    '\u{10b60}'..='\u{10b72}',
+    '\u{10b80}'..='\u{10b91}',
+    '\u{10c00}'..='\u{10c48}',
+    '\u{10c80}'..='\u{10cb2}',
+    '\u{10cc0}'..='\u{10cf2}',
+    '\u{10d00}'..='\u{10d27}',
+    '\u{10d30}'..='\u{10d39}',
+    '\u{10d40}'..='\u{10d65}',
+    '\u{10d69}'..='\u{10d6d}',
+    '\u{10d6f}'..='\u{10d85}',
+    '\u{10e80}'..='\u{10ea9}',
+    '\u{10eab}'..='\u{10eac}',
+    '\u{10eb0}'..='\u{10eb1}',
+    '\u{10ec2}'..='\u{10ec7}',
+    '\u{10efa}'..='\u{10f1c}',
+    '\u{10f27}'..='\u{10f27}',
+    '\u{10f30}'..='\u{10f50}',
+    '\u{10f70}'..='\u{10f85}',
+    '\u{10fb0}'..='\u{10fc4}',
+    '\u{10fe0}'..='\u{10ff6}',
+    '\u{11000}'..='\u{11046}',
+    '\u{11066}'..='\u{11075}',
+    '\u{1107f}'..='\u{110ba}',
+    '\u{110c2}'..='\u{110c2}',
+    '\u{110d0}'..='\u{110e8}',
+    '\u{110f0}'..='\u{110f9}',
+    '\u{11100}'..='\u{11134}',
+    '\u{11136}'..='\u{1113f}',
+    '\u{11144}'..='\u{11147}',
+    '\u{11150}'..='\u{11173}',
+    '\u{11176}'..='\u{11176}',
+    '\u{11180}'..='\u{111c4}',
+    '\u{111c9}'..='\u{111cc}',
+    '\u{111ce}'..='\u{111da}',
+    '\u{111dc}'..='\u{111dc}',
+    '\u{11200}'..='\u{11211}',
+    '\u{11213}'..='\u{11237}',
+    '\u{1123e}'..='\u{11241}',
+    '\u{11280}'..='\u{11286}',
+    '\u{11288}'..='\u{11288}',
+    '\u{1128a}'..='\u{1128d}',
+    '\u{1128f}'..='\u{1129d}',
+    '\u{1129f}'..='\u{112a8}',
+    '\u{112b0}'..='\u{112ea}',
+    '\u{112f0}'..='\u{112f9}',
+    '\u{11300}'..='\u{11303}',
+    '\u{11305}'..='\u{1130c}',
+    '\u{1130f}'..='\u{11310}',
+    '\u{11313}'..='\u{11328}',
+    '\u{1132a}'..='\u{11330}',
+    '\u{11332}'..='\u{11333}',
+    '\u{11335}'..='\u{11339}',
+    '\u{1133b}'..='\u{11344}',
+    '\u{11347}'..='\u{11348}',
+    '\u{1134b}'..='\u{1134d}',
+    '\u{11350}'..='\u{11350}',
+    '\u{11357}'..='\u{11357}',
+    '\u{1135d}'..='\u{11363}',
+    '\u{11366}'..='\u{1136c}',
+    '\u{11370}'..='\u{11374}',
+    '\u{11380}'..='\u{11389}',
+    '\u{1138b}'..='\u{1138b}',
+    '\u{1138e}'..='\u{1138e}',
+    '\u{11390}'..='\u{113b5}',
+    '\u{113b7}'..='\u{113c0}',
+    '\u{113c2}'..='\u{113c2}',
+    '\u{113c5}'..='\u{113c5}',
+    '\u{113c7}'..='\u{113ca}',
+    '\u{113cc}'..='\u{113d3}',
+    '\u{113e1}'..='\u{113e2}',
+    '\u{11400}'..='\u{1144a}',
+    '\u{11450}'..='\u{11459}',
+    '\u{1145e}'..='\u{11461}',
+    '\u{11480}'..='\u{114c5}',
+    '\u{114c7}'..='\u{114c7}',
+    '\u{114d0}'..='\u{114d9}',
+    '\u{11580}'..='\u{115b5}',
+    '\u{115b8}'..='\u{115c0}',
+    '\u{115d8}'..='\u{115dd}',
+    '\u{11600}'..='\u{11640}',
+    '\u{11644}'..='\u{11644}',
+    '\u{11650}'..='\u{11659}',
+    '\u{11680}'..='\u{116b8}',
+    '\u{116c0}'..='\u{116c9}',
+    '\u{116d0}'..='\u{116e3}',
+    '\u{11700}'..='\u{1171a}',
+    '\u{1171d}'..='\u{1172b}',
+    '\u{11730}'..='\u{11739}',
+    '\u{11740}'..='\u{11746}',
+    '\u{11800}'..='\u{1183a}',
+    '\u{118a0}'..='\u{118e9}',
+    '\u{118ff}'..='\u{11906}',
+    '\u{11909}'..='\u{11909}',
+    '\u{1190c}'..='\u{11913}',
+    '\u{11915}'..='\u{11916}',
+    '\u{11918}'..='\u{11935}',
+    '\u{11937}'..='\u{11938}',
+    '\u{1193b}'..='\u{11943}',
+    '\u{11950}'..='\u{11959}',
+    '\u{119a0}'..='\u{119a7}',
+    '\u{119aa}'..='\u{119d7}',
+    '\u{119da}'..='\u{119e1}',
+    '\u{119e3}'..='\u{119e4}',
+    '\u{11a00}'..='\u{11a3e}',
+    '\u{11a47}'..='\u{11a47}',
+    '\u{11a50}'..='\u{11a99}',
+    '\u{11a9d}'..='\u{11a9d}',
+    '\u{11ab0}'..='\u{11af8}',
+    '\u{11b60}'..='\u{11b67}',
+    '\u{11bc0}'..='\u{11be0}',
+    '\u{11bf0}'..='\u{11bf9}',
+    '\u{11c00}'..='\u{11c08}',
+    '\u{11c0a}'..='\u{11c36}',
+    '\u{11c38}'..='\u{11c40}',
+    '\u{11c50}'..='\u{11c59}',
+    '\u{11c72}'..='\u{11c8f}',
+    '\u{11c92}'..='\u{11ca7}',
+    '\u{11ca9}'..='\u{11cb6}',
+    '\u{11d00}'..='\u{11d06}',
+    '\u{11d08}'..='\u{11d09}',
+    '\u{11d0b}'..='\u{11d36}',
+    '\u{11d3a}'..='\u{11d3a}',
+    '\u{11d3c}'..='\u{11d3d}',
+    '\u{11d3f}'..='\u{11d47}',
+    '\u{11d50}'..='\u{11d59}',
+    '\u{11d60}'..='\u{11d65}',
+    '\u{11d67}'..='\u{11d68}',
+    '\u{11d6a}'..='\u{11d8e}',
+    '\u{11d90}'..='\u{11d91}',
+    '\u{11d93}'..='\u{11d98}',
+    '\u{11da0}'..='\u{11da9}',
+    '\u{11db0}'..='\u{11ddb}',
+    '\u{11de0}'..='\u{11de9}',
+    '\u{11ee0}'..='\u{11ef6}',
+    '\u{11f00}'..='\u{11f10}',
+    '\u{11f12}'..='\u{11f3a}',
+    '\u{11f3e}'..='\u{11f42}',
+    '\u{11f50}'..='\u{11f5a}',
+    '\u{11fb0}'..='\u{11fb0}',
+    '\u{12000}'..='\u{12399}',
+    '\u{12400}'..='\u{1246e}',
+    '\u{12480}'..='\u{12543}',
+    '\u{12f90}'..='\u{12ff0}',
+    '\u{13000}'..='\u{1342f}',
+    '\u{13440}'..='\u{13455}',
+    '\u{13460}'..='\u{143fa}',
+    '\u{14400}'..='\u{14646}',
+    '\u{16100}'..='\u{16139}',
+    '\u{16800}'..='\u{16a38}',
+    '\u{16a40}'..='\u{16a5e}',
+    '\u{16a60}'..='\u{16a69}',
+    '\u{16a70}'..='\u{16abe}',
+    '\u{16ac0}'..='\u{16ac9}',
+    '\u{16ad0}'..='\u{16aed}',
+    '\u{16af0}'..='\u{16af4}',
+    '\u{16b00}'..='\u{16b36}',
+    '\u{16b40}'..='\u{16b43}',
+    '\u{16b50}'..='\u{16b59}',
+    '\u{16b63}'..='\u{16b77}',
+    '\u{16b7d}'..='\u{16b8f}',
+    '\u{16d40}'..='\u{16d6c}',
+    '\u{16d70}'..='\u{16d79}',
+    '\u{16e40}'..='\u{16e7f}',
+    '\u{16ea0}'..='\u{16eb8}',
+    '\u{16ebb}'..='\u{16ed3}',
+    '\u{16f00}'..='\u{16f4a}',
+    '\u{16f4f}'..='\u{16f87}',
+    '\u{16f8f}'..='\u{16f9f}',
+    '\u{16fe0}'..='\u{16fe1}',
+    '\u{16fe3}'..='\u{16fe4}',
+    '\u{16ff0}'..='\u{16ff6}',
+    '\u{17000}'..='\u{18cd5}',
+    '\u{18cff}'..='\u{18d1e}',
+    '\u{18d80}'..='\u{18df2}',
+    '\u{1aff0}'..='\u{1aff3}',
+    '\u{1aff5}'..='\u{1affb}',
+    '\u{1affd}'..='\u{1affe}',
+    '\u{1b000}'..='\u{1b122}',
+    '\u{1b132}'..='\u{1b132}',
+    '\u{1b150}'..='\u{1b152}',
+    '\u{1b155}'..='\u{1b155}',
+    '\u{1b164}'..='\u{1b167}',
+    '\u{1b170}'..='\u{1b2fb}',
+    '\u{1bc00}'..='\u{1bc6a}',
+    '\u{1bc70}'..='\u{1bc7c}',
+    '\u{1bc80}'..='\u{1bc88}',
+    '\u{1bc90}'..='\u{1bc99}',
+    '\u{1bc9d}'..='\u{1bc9e}',
+    '\u{1ccf0}'..='\u{1ccf9}',
+    '\u{1cf00}'..='\u{1cf2d}',
+    '\u{1cf30}'..='\u{1cf46}',
+    '\u{1d165}'..='\u{1d169}',
+    '\u{1d16d}'..='\u{1d172}',
+    '\u{1d17b}'..='\u{1d182}',
+    '\u{1d185}'..='\u{1d18b}',
+    '\u{1d1aa}'..='\u{1d1ad}',
+    '\u{1d242}'..='\u{1d244}',
+    '\u{1d400}'..='\u{1d454}',
+    '\u{1d456}'..='\u{1d49c}',
+    '\u{1d49e}'..='\u{1d49f}',
+    '\u{1d4a2}'..='\u{1d4a2}',
+    '\u{1d4a5}'..='\u{1d4a6}',
+    '\u{1d4a9}'..='\u{1d4ac}',
+    '\u{1d4ae}'..='\u{1d4b9}',
+    '\u{1d4bb}'..='\u{1d4bb}',
+    '\u{1d4bd}'..='\u{1d4c3}',
+    '\u{1d4c5}'..='\u{1d505}',
+    '\u{1d507}'..='\u{1d50a}',
+    '\u{1d50d}'..='\u{1d514}',
+    '\u{1d516}'..='\u{1d51c}',
+    '\u{1d51e}'..='\u{1d539}',
+    '\u{1d53b}'..='\u{1d53e}',
+    '\u{1d540}'..='\u{1d544}',
+    '\u{1d546}'..='\u{1d546}',
+    '\u{1d54a}'..='\u{1d550}',
+    '\u{1d552}'..='\u{1d6a5}',
+    '\u{1d6a8}'..='\u{1d6c0}',
+    '\u{1d6c2}'..='\u{1d6da}',
+    '\u{1d6dc}'..='\u{1d6fa}',
+    '\u{1d6fc}'..='\u{1d714}',
+    '\u{1d716}'..='\u{1d734}',
+    '\u{1d736}'..='\u{1d74e}',
+    '\u{1d750}'..='\u{1d76e}',
+    '\u{1d770}'..='\u{1d788}',
+    '\u{1d78a}'..='\u{1d7a8}',
+    '\u{1d7aa}'..='\u{1d7c2}',
+    '\u{1d7c4}'..='\u{1d7cb}',
+    '\u{1d7ce}'..='\u{1d7ff}',
+    '\u{1da00}'..='\u{1da36}',
+    '\u{1da3b}'..='\u{1da6c}',
+    '\u{1da75}'..='\u{1da75}',
+    '\u{1da84}'..='\u{1da84}',
+    '\u{1da9b}'..='\u{1da9f}',
+    '\u{1daa1}'..='\u{1daaf}',
+    '\u{1df00}'..='\u{1df1e}',
+    '\u{1df25}'..='\u{1df2a}',
+    '\u{1e000}'..='\u{1e006}',
+    '\u{1e008}'..='\u{1e018}',
+    '\u{1e01b}'..='\u{1e021}',
+    '\u{1e023}'..='\u{1e024}',
+    '\u{1e026}'..='\u{1e02a}',
+    '\u{1e030}'..='\u{1e06d}',
+    '\u{1e08f}'..='\u{1e08f}',
+    '\u{1e100}'..='\u{1e12c}',
+    '\u{1e130}'..='\u{1e13d}',
+    '\u{1e140}'..='\u{1e149}',
+    '\u{1e14e}'..='\u{1e14e}',
+    '\u{1e290}'..='\u{1e2ae}',
+    '\u{1e2c0}'..='\u{1e2f9}',
+    '\u{1e4d0}'..='\u{1e4f9}',
+    '\u{1e5d0}'..='\u{1e5fa}',
+    '\u{1e6c0}'..='\u{1e6de}',
+    '\u{1e6e0}'..='\u{1e6f5}',
+    '\u{1e6fe}'..='\u{1e6ff}',
+    '\u{1e7e0}'..='\u{1e7e6}',
+    '\u{1e7e8}'..='\u{1e7eb}',
+    '\u{1e7ed}'..='\u{1e7ee}',
+    '\u{1e7f0}'..='\u{1e7fe}',
+    '\u{1e800}'..='\u{1e8c4}',
+    '\u{1e8d0}'..='\u{1e8d6}',
+    '\u{1e900}'..='\u{1e94b}',
+    '\u{1e950}'..='\u{1e959}',
+    '\u{1ee00}'..='\u{1ee03}',
+    '\u{1ee05}'..='\u{1ee1f}',
+    '\u{1ee21}'..='\u{1ee22}',
+    '\u{1ee24}'..='\u{1ee24}',
+    '\u{1ee27}'..='\u{1ee27}',
+    '\u{1ee29}'..='\u{1ee32}',
+    '\u{1ee34}'..='\u{1ee37}',
+    '\u{1ee39}'..='\u{1ee39}',
+    '\u{1ee3b}'..='\u{1ee3b}',
+    '\u{1ee42}'..='\u{1ee42}',
+    '\u{1ee47}'..='\u{1ee47}',
+    '\u{1ee49}'..='\u{1ee49}',
+    '\u{1ee4b}'..='\u{1ee4b}',
+    '\u{1ee4d}'..='\u{1ee4f}',
+    '\u{1ee51}'..='\u{1ee52}',
+    '\u{1ee54}'..='\u{1ee54}',
+    '\u{1ee57}'..='\u{1ee57}',
+    '\u{1ee59}'..='\u{1ee59}',
+    '\u{1ee5b}'..='\u{1ee5b}',
+    '\u{1ee5d}'..='\u{1ee5d}',
+    '\u{1ee5f}'..='\u{1ee5f}',
+    '\u{1ee61}'..='\u{1ee62}',
+    '\u{1ee64}'..='\u{1ee64}',
+    '\u{1ee67}'..='\u{1ee6a}',
+    '\u{1ee6c}'..='\u{1ee72}',
+    '\u{1ee74}'..='\u{1ee77}',
+    '\u{1ee79}'..='\u{1ee7c}',
+    '\u{1ee7e}'..='\u{1ee7e}',
+    '\u{1ee80}'..='\u{1ee89}',
+    '\u{1ee8b}'..='\u{1ee9b}',
+    '\u{1eea1}'..='\u{1eea3}',
+    '\u{1eea5}'..='\u{1eea9}',
+    '\u{1eeab}'..='\u{1eebb}',
+    '\u{1fbf0}'..='\u{1fbf9}',
+    '\u{20000}'..='\u{2a6df}',
+    '\u{2a700}'..='\u{2b81d}',
+    '\u{2b820}'..='\u{2cead}',
+    '\u{2ceb0}'..='\u{2ebe0}',
+    '\u{2ebf0}'..='\u{2ee5d}',
+    '\u{2f800}'..='\u{2fa1d}',
+    '\u{30000}'..='\u{3134a}',
+    '\u{31350}'..='\u{33479}',
+    '\u{e0100}'..='\u{e01ef}',
+];
+
+pub(crate) static WHITE_SPACE: &[CharClassRange] = &[
+    '\u{9}'..='\u{d}',
+    '\u{20}'..='\u{20}',
+    '\u{85}'..='\u{85}',
+    '\u{a0}'..='\u{a0}',
+    '\u{1680}'..='\u{1680}',
+    '\u{2000}'..='\u{200a}',
+    '\u{2028}'..='\u{2029}',
+    '\u{202f}'..='\u{202f}',
+    '\u{205f}'..='\u{205f}',
+    '\u{3000}'..='\u{3000}',
+];
+
+pub(crate) static ADLAM: &[CharClassRange] = &[
+    '\u{1e900}'..='\u{1e94b}',
+    '\u{1e950}'..='\u{1e959}',
+    '\u{1e95e}'..='\u{1e95f}',
+];
+
+pub(crate) static AHOM: &[CharClassRange] = &[
+    '\u{11700}'..='\u{1171a}',
+    '\u{1171d}'..='\u{1172b}',
+    '\u{11730}'..='\u{11746}',
+];
+
+pub(crate) static ANATOLIAN_HIEROGLYPHS: &[CharClassRange] = &[
+    '\u{14400}'..='\u{14646}',
+];
+
+pub(crate) static ARABIC: &[CharClassRange] = &[
+    '\u{600}'..='\u{604}',
+    '\u{606}'..='\u{60b}',
+    '\u{60d}'..='\u{61a}',
+    '\u{61c}'..='\u{61e}',
+    '\u{620}'..='\u{63f}',
+    '\u{641}'..='\u{64a}',
+    '\u{656}'..='\u{66f}',
+    '\u{671}'..='\u{6dc}',
+    '\u{6de}'..='\u{6ff}',
+    '\u{750}'..='\u{77f}',
+    '\u{870}'..='\u{891}',
+    '\u{897}'..='\u{8e1}',
+    '\u{8e3}'..='\u{8ff}',
+    '\u{fb50}'..='\u{fd3d}',
+    '\u{fd40}'..='\u{fdcf}',
+    '\u{fdf0}'..='\u{fdff}',
+    '\u{fe70}'..='\u{fe74}',
+    '\u{fe76}'..='\u{fefc}',
+    '\u{10e60}'..='\u{10e7e}',
+    '\u{10ec2}'..='\u{10ec7}',
+    '\u{10ed0}'..='\u{10ed8}',
+    '\u{10efa}'..='\u{10eff}',
+    '\u{1ee00}'..='\u{1ee03}',
+    '\u{1ee05}'..='\u{1ee1f}',
+    '\u{1ee21}'..='\u{1ee22}',
+    '\u{1ee24}'..='\u{1ee24}',
+    '\u{1ee27}'..='\u{1ee27}',
+    '\u{1ee29}'..='\u{1ee32}',
+    '\u{1ee34}'..='\u{1ee37}',
+    '\u{1ee39}'..='\u{1ee39}',
+    '\u{1ee3b}'..='\u{1ee3b}',
+    '\u{1ee42}'..='\u{1ee42}',
+    '\u{1ee47}'..='\u{1ee47}',
+    '\u{1ee49}'..='\u{1ee49}',
+    '\u{1ee4b}'..='\u{1ee4b}',
+    '\u{1ee4d}'..='\u{1ee4f}',
+    '\u{1ee51}'..='\u{1ee52}',
+    '\u{1ee54}'..='\u{1ee54}',
+    '\u{1ee57}'..='\u{1ee57}',
+    '\u{1ee59}'..='\u{1ee59}',
+    '\u{1ee5b}'..='\u{1ee5b}',
+    '\u{1ee5d}'..='\u{1ee5d}',
+    '\u{1ee5f}'..='\u{1ee5f}',
+    '\u{1ee61}'..='\u{1ee62}',
+    '\u{1ee64}'..='\u{1ee64}',
+    '\u{1ee67}'..='\u{1ee6a}',
+    '\u{1ee6c}'..='\u{1ee72}',
+    '\u{1ee74}'..='\u{1ee77}',
+    '\u{1ee79}'..='\u{1ee7c}',
+    '\u{1ee7e}'..='\u{1ee7e}',
+    '\u{1ee80}'..='\u{1ee89}',
+    '\u{1ee8b}'..='\u{1ee9b}',
+    '\u{1eea1}'..='\u{1eea3}',
+    '\u{1eea5}'..='\u{1eea9}',
+    '\u{1eeab}'..='\u{1eebb}',
+    '\u{1eef0}'..='\u{1eef1}',
+];
+
+pub(crate) static ARMENIAN: &[CharClassRange] = &[
+    '\u{531}'..='\u{556}',
+    '\u{559}'..='\u{58a}',
+    '\u{58d}'..='\u{58f}',
+    '\u{fb13}'..='\u{fb17}',
+];
+
+pub(crate) static AVESTAN: &[CharClassRange] = &[
+    '\u{10b00}'..='\u{10b35}',
+    '\u{10b39}'..='\u{10b3f}',
+];
+
+pub(crate) static BALINESE: &[CharClassRange] = &[
+    '\u{1b00}'..='\u{1b4c}',
+    '\u{1b4e}'..='\u{1b7f}',
+];
+
+pub(crate) static BAMUM: &[CharClassRange] = &[
+    '\u{a6a0}'..='\u{a6f7}',
+    '\u{16800}'..='\u{16a38}',
+];
+
+pub(crate) static BASSA_VAH: &[CharClassRange] = &[
+    '\u{16ad0}'..='\u{16aed}',
+    '\u{16af0}'..='\u{16af5}',
+];
+
+pub(crate) static BATAK: &[CharClassRange] = &[
+    '\u{1bc0}'..='\u{1bf3}',
+    '\u{1bfc}'..='\u{1bff}',
+];
+
+pub(crate) static BENGALI: &[CharClassRange] = &[
+    '\u{980}'..='\u{983}',
+    '\u{985}'..='\u{98c}',
+    '\u{98f}'..='\u{990}',
+    '\u{993}'..='\u{9a8}',
+    '\u{9aa}'..='\u{9b0}',
+    '\u{9b2}'..='\u{9b2}',
+    '\u{9b6}'..='\u{9b9}',
+    '\u{9bc}'..='\u{9c4}',
+    '\u{9c7}'..='\u{9c8}',
+    '\u{9cb}'..='\u{9ce}',
+    '\u{9d7}'..='\u{9d7}',
+    '\u{9dc}'..='\u{9dd}',
+    '\u{9df}'..='\u{9e3}',
+    '\u{9e6}'..='\u{9fe}',
+];
+
+pub(crate) static BERIA_ERFE: &[CharClassRange] = &[
+    '\u{16ea0}'..='\u{16eb8}',
+    '\u{16ebb}'..='\u{16ed3}',
+];
+
+pub(crate) static BHAIKSUKI: &[CharClassRange] = &[
+    '\u{11c00}'..='\u{11c08}',
+    '\u{11c0a}'..='\u{11c36}',
+    '\u{11c38}'..='\u{11c45}',
+    '\u{11c50}'..='\u{11c6c}',
+];
+
+pub(crate) static BOPOMOFO: &[CharClassRange] = &[
+    '\u{2ea}'..='\u{2eb}',
+    '\u{3105}'..='\u{312f}',
+    '\u{31a0}'..='\u{31bf}',
+];
+
+pub(crate) static BRAHMI: &[CharClassRange] = &[
+    '\u{11000}'..='\u{1104d}',
+    '\u{11052}'..='\u{11075}',
+    '\u{1107f}'..='\u{1107f}',
+];
+
+pub(crate) static BRAILLE: &[CharClassRange] = &[
+    '\u{2800}'..='\u{28ff}',
+];
+
+pub(crate) static BUGINESE: &[CharClassRange] = &[
+    '\u{1a00}'..='\u{1a1b}',
+    '\u{1a1e}'..='\u{1a1f}',
+];
+
+pub(crate) static BUHID: &[CharClassRange] = &[
+    '\u{1740}'..='\u{1753}',
+];
+
+pub(crate) static CANADIAN_ABORIGINAL: &[CharClassRange] = &[
+    '\u{1400}'..='\u{167f}',
+    '\u{18b0}'..='\u{18f5}',
+    '\u{11ab0}'..='\u{11abf}',
+];
+
+pub(crate) static CARIAN: &[CharClassRange] = &[
+    '\u{102a0}'..='\u{102d0}',
+];
+
+pub(crate) static CAUCASIAN_ALBANIAN: &[CharClassRange] = &[
+    '\u{10530}'..='\u{10563}',
+    '\u{1056f}'..='\u{1056f}',
+];
+
+pub(crate) static CHAKMA: &[CharClassRange] = &[
+    '\u{11100}'..='\u{11134}',
+    '\u{11136}'..='\u{11147}',
+];
+
+pub(crate) static CHAM: &[CharClassRange] = &[
+    '\u{aa00}'..='\u{aa36}',
+    '\u{aa40}'..='\u{aa4d}',
+    '\u{aa50}'..='\u{aa59}',
+    '\u{aa5c}'..='\u{aa5f}',
+];
+
+pub(crate) static CHEROKEE: &[CharClassRange] = &[
+    '\u{13a0}'..='\u{13f5}',
+    '\u{13f8}'..='\u{13fd}',
+    '\u{ab70}'..='\u{abbf}',
+];
+
+pub(crate) static CHORASMIAN: &[CharClassRange] = &[
+    '\u{10fb0}'..='\u{10fcb}',
+];
+
+pub(crate) static COMMON: &[CharClassRange] = &[
+    '\u{0}'..='\u{40}',
+    '\u{5b}'..='\u{60}',
+    '\u{7b}'..='\u{a9}',
+    '\u{ab}'..='\u{b9}',
+    '\u{bb}'..='\u{bf}',
+    '\u{d7}'..='\u{d7}',
+    '\u{f7}'..='\u{f7}',
+    '\u{2b9}'..='\u{2df}',
+    '\u{2e5}'..='\u{2e9}',
+    '\u{2ec}'..='\u{2ff}',
+    '\u{374}'..='\u{374}',
+    '\u{37e}'..='\u{37e}',
+    '\u{385}'..='\u{385}',
+    '\u{387}'..='\u{387}',
+    '\u{605}'..='\u{605}',
+    '\u{60c}'..='\u{60c}',
+    '\u{61b}'..='\u{61b}',
+    '\u{61f}'..='\u{61f}',
+    '\u{640}'..='\u{640}',
+    '\u{6dd}'..='\u{6dd}',
+    '\u{8e2}'..='\u{8e2}',
+    '\u{964}'..='\u{965}',
+    '\u{e3f}'..='\u{e3f}',
+    '\u{fd5}'..='\u{fd8}',
+    '\u{10fb}'..='\u{10fb}',
+    '\u{16eb}'..='\u{16ed}',
+    '\u{1735}'..='\u{1736}',
+    '\u{1802}'..='\u{1803}',
+    '\u{1805}'..='\u{1805}',
+    '\u{1cd3}'..='\u{1cd3}',
+    '\u{1ce1}'..='\u{1ce1}',
+    '\u{1ce9}'..='\u{1cec}',
+    '\u{1cee}'..='\u{1cf3}',
+    '\u{1cf5}'..='\u{1cf7}',
+    '\u{1cfa}'..='\u{1cfa}',
+    '\u{2000}'..='\u{200b}',
+    '\u{200e}'..='\u{2064}',
+    '\u{2066}'..='\u{2070}',
+    '\u{2074}'..='\u{207e}',
+    '\u{2080}'..='\u{208e}',
+    '\u{20a0}'..='\u{20c1}',
+    '\u{2100}'..='\u{2125}',
+    '\u{2127}'..='\u{2129}',
+    '\u{212c}'..='\u{2131}',
+    '\u{2133}'..='\u{214d}',
+    '\u{214f}'..='\u{215f}',
+    '\u{2189}'..='\u{218b}',
+    '\u{2190}'..='\u{2429}',
+    '\u{2440}'..='\u{244a}',
+    '\u{2460}'..='\u{27ff}',
+    '\u{2900}'..='\u{2b73}',
+    '\u{2b76}'..='\u{2bff}',
+    '\u{2e00}'..='\u{2e5d}',
+    '\u{2ff0}'..='\u{3004}',
+    '\u{3006}'..='\u{3006}',
+    '\u{3008}'..='\u{3020}',
+    '\u{3030}'..='\u{3037}',
+    '\u{303c}'..='\u{303f}',
+    '\u{309b}'..='\u{309c}',
+    '\u{30a0}'..='\u{30a0}',
+    '\u{30fb}'..='\u{30fc}',
+    '\u{3190}'..='\u{319f}',
+    '\u{31c0}'..='\u{31e5}',
+    '\u{31ef}'..='\u{31ef}',
+    '\u{3220}'..='\u{325f}',
+    '\u{327f}'..='\u{32cf}',
+    '\u{32ff}'..='\u{32ff}',
+    '\u{3358}'..='\u{33ff}',
+    '\u{4dc0}'..='\u{4dff}',
+    '\u{a700}'..='\u{a721}',
+    '\u{a788}'..='\u{a78a}',
+    '\u{a830}'..='\u{a839}',
+    '\u{a92e}'..='\u{a92e}',
+    '\u{a9cf}'..='\u{a9cf}',
+    '\u{ab5b}'..='\u{ab5b}',
+    '\u{ab6a}'..='\u{ab6b}',
+    '\u{fd3e}'..='\u{fd3f}',
+    '\u{fe10}'..='\u{fe19}',
+    '\u{fe30}'..='\u{fe52}',
+    '\u{fe54}'..='\u{fe66}',
+    '\u{fe68}'..='\u{fe6b}',
+    '\u{feff}'..='\u{feff}',
+    '\u{ff01}'..='\u{ff20}',
+    '\u{ff3b}'..='\u{ff40}',
+    '\u{ff5b}'..='\u{ff65}',
+    '\u{ff70}'..='\u{ff70}',
+    '\u{ff9e}'..='\u{ff9f}',
+    '\u{ffe0}'..='\u{ffe6}',
+    '\u{ffe8}'..='\u{ffee}',
+    '\u{fff9}'..='\u{fffd}',
+    '\u{10100}'..='\u{10102}',
+    '\u{10107}'..='\u{10133}',
+    '\u{10137}'..='\u{1013f}',
+    '\u{10190}'..='\u{1019c}',
+    '\u{101d0}'..='\u{101fc}',
+    '\u{102e1}'..='\u{102fb}',
+    '\u{1bca0}'..='\u{1bca3}',
+    '\u{1cc00}'..='\u{1ccfc}',
+    '\u{1cd00}'..='\u{1ceb3}',
+    '\u{1ceba}'..='\u{1ced0}',
+    '\u{1cee0}'..='\u{1cef0}',
+    '\u{1cf50}'..='\u{1cfc3}',
+    '\u{1d000}'..='\u{1d0f5}',
+    '\u{1d100}'..='\u{1d126}',
+    '\u{1d129}'..='\u{1d166}',
+    '\u{1d16a}'..='\u{1d17a}',
+    '\u{1d183}'..='\u{1d184}',
+    '\u{1d18c}'..='\u{1d1a9}',
+    '\u{1d1ae}'..='\u{1d1ea}',
+    '\u{1d2c0}'..='\u{1d2d3}',
+    '\u{1d2e0}'..='\u{1d2f3}',
+    '\u{1d300}'..='\u{1d356}',
+    '\u{1d360}'..='\u{1d378}',
+    '\u{1d400}'..='\u{1d454}',
+    '\u{1d456}'..='\u{1d49c}',
+    '\u{1d49e}'..='\u{1d49f}',
+    '\u{1d4a2}'..='\u{1d4a2}',
+    '\u{1d4a5}'..='\u{1d4a6}',
+    '\u{1d4a9}'..='\u{1d4ac}',
+    '\u{1d4ae}'..='\u{1d4b9}',
+    '\u{1d4bb}'..='\u{1d4bb}',
+    '\u{1d4bd}'..='\u{1d4c3}',
+    '\u{1d4c5}'..='\u{1d505}',
+    '\u{1d507}'..='\u{1d50a}',
+    '\u{1d50d}'..='\u{1d514}',
+    '\u{1d516}'..='\u{1d51c}',
+    '\u{1d51e}'..='\u{1d539}',
+    '\u{1d53b}'..='\u{1d53e}',
+    '\u{1d540}'..='\u{1d544}',
+    '\u{1d546}'..='\u{1d546}',
+    '\u{1d54a}'..='\u{1d550}',
+    '\u{1d552}'..='\u{1d6a5}',
+    '\u{1d6a8}'..='\u{1d7cb}',
+    '\u{1d7ce}'..='\u{1d7ff}',
+    '\u{1ec71}'..='\u{1ecb4}',
+    '\u{1ed01}'..='\u{1ed3d}',
+    '\u{1f000}'..='\u{1f02b}',
+    '\u{1f030}'..='\u{1f093}',
+    '\u{1f0a0}'..='\u{1f0ae}',
+    '\u{1f0b1}'..='\u{1f0bf}',
+    '\u{1f0c1}'..='\u{1f0cf}',
+    '\u{1f0d1}'..='\u{1f0f5}',
+    '\u{1f100}'..='\u{1f1ad}',
+    '\u{1f1e6}'..='\u{1f1ff}',
+    '\u{1f201}'..='\u{1f202}',
+    '\u{1f210}'..='\u{1f23b}',
+    '\u{1f240}'..='\u{1f248}',
+    '\u{1f250}'..='\u{1f251}',
+    '\u{1f260}'..='\u{1f265}',
+    '\u{1f300}'..='\u{1f6d8}',
+    '\u{1f6dc}'..='\u{1f6ec}',
+    '\u{1f6f0}'..='\u{1f6fc}',
+    '\u{1f700}'..='\u{1f7d9}',
+    '\u{1f7e0}'..='\u{1f7eb}',
+    '\u{1f7f0}'..='\u{1f7f0}',
+    '\u{1f800}'..='\u{1f80b}',
+    '\u{1f810}'..='\u{1f847}',
+    '\u{1f850}'..='\u{1f859}',
+    '\u{1f860}'..='\u{1f887}',
+    '\u{1f890}'..='\u{1f8ad}',
+    '\u{1f8b0}'..='\u{1f8bb}',
+    '\u{1f8c0}'..='\u{1f8c1}',
+    '\u{1f8d0}'..='\u{1f8d8}',
+    '\u{1f900}'..='\u{1fa57}',
+    '\u{1fa60}'..='\u{1fa6d}',
+    '\u{1fa70}'..='\u{1fa7c}',
+    '\u{1fa80}'..='\u{1fa8a}',
+    '\u{1fa8e}'..='\u{1fac6}',
+    '\u{1fac8}'..='\u{1fac8}',
+    '\u{1facd}'..='\u{1fadc}',
+    '\u{1fadf}'..='\u{1faea}',
+    '\u{1faef}'..='\u{1faf8}',
+    '\u{1fb00}'..='\u{1fb92}',
+    '\u{1fb94}'..='\u{1fbfa}',
+    '\u{e0001}'..='\u{e0001}',
+    '\u{e0020}'..='\u{e007f}',
+];
+
+pub(crate) static COPTIC: &[CharClassRange] = &[
+    '\u{3e2}'..='\u{3ef}',
+    '\u{2c80}'..='\u{2cf3}',
+    '\u{2cf9}'..='\u{2cff}',
+];
+
+pub(crate) static CUNEIFORM: &[CharClassRange] = &[
+    '\u{12000}'..='\u{12399}',
+    '\u{12400}'..='\u{1246e}',
+    '\u{12470}'..='\u{12474}',
+    '\u{12480}'..='\u{12543}',
+];
+
+pub(crate) static CYPRIOT: &[CharClassRange] = &[
+    '\u{10800}'..='\u{10805}',
+    '\u{10808}'..='\u{10808}',
+    '\u{1080a}'..='\u{10835}',
+    '\u{10837}'..='\u{10838}',
+    '\u{1083c}'..='\u{1083c}',
+    '\u{1083f}'..='\u{1083f}',
+];
+
+pub(crate) static CYPRO_MINOAN: &[CharClassRange] = &[
+    '\u{12f90}'..='\u{12ff2}',
+];
+
+pub(crate) static CYRILLIC: &[CharClassRange] = &[
+    '\u{400}'..='\u{484}',
+    '\u{487}'..='\u{52f}',
+    '\u{1c80}'..='\u{1c8a}',
+    '\u{1d2b}'..='\u{1d2b}',
+    '\u{1d78}'..='\u{1d78}',
+    '\u{2de0}'..='\u{2dff}',
+    '\u{a640}'..='\u{a69f}',
+    '\u{fe2e}'..='\u{fe2f}',
+    '\u{1e030}'..='\u{1e06d}',
+    '\u{1e08f}'..='\u{1e08f}',
+];
+
+pub(crate) static DESERET: &[CharClassRange] = &[
+    '\u{10400}'..='\u{1044f}',
+];
+
+pub(crate) static DEVANAGARI: &[CharClassRange] = &[
+    '\u{900}'..='\u{950}',
+    '\u{955}'..='\u{963}',
+    '\u{966}'..='\u{97f}',
+    '\u{a8e0}'..='\u{a8ff}',
+    '\u{11b00}'..='\u{11b09}',
+];
+
+pub(crate) static DIVES_AKURU: &[CharClassRange] = &[
+    '\u{11900}'..='\u{11906}',
+    '\u{11909}'..='\u{11909}',
+    '\u{1190c}'..='\u{11913}',
+    '\u{11915}'..='\u{11916}',
+    '\u{11918}'..='\u{11935}',
+    '\u{11937}'..='\u{11938}',
+    '\u{1193b}'..='\u{11946}',
+    '\u{11950}'..='\u{11959}',
+];
+
+pub(crate) static DOGRA: &[CharClassRange] = &[
+    '\u{11800}'..='\u{1183b}',
+];
+
+pub(crate) static DUPLOYAN: &[CharClassRange] = &[
+    '\u{1bc00}'..='\u{1bc6a}',
+    '\u{1bc70}'..='\u{1bc7c}',
+    '\u{1bc80}'..='\u{1bc88}',
+    '\u{1bc90}'..='\u{1bc99}',
+    '\u{1bc9c}'..='\u{1bc9f}',
+];
+
+pub(crate) static EGYPTIAN_HIEROGLYPHS: &[CharClassRange] = &[
+    '\u{13000}'..='\u{13455}',
+    '\u{13460}'..='\u{143fa}',
+];
+
+pub(crate) static ELBASAN: &[CharClassRange] = &[
+    '\u{10500}'..='\u{10527}',
+];
+
+pub(crate) static ELYMAIC: &[CharClassRange] = &[
+    '\u{10fe0}'..='\u{10ff6}',
+];
+
+pub(crate) static ETHIOPIC: &[CharClassRange] = &[
+    '\u{1200}'..='\u{1248}',
+    '\u{124a}'..='\u{124d}',
+    '\u{1250}'..='\u{1256}',
+    '\u{1258}'..='\u{1258}',
+    '\u{125a}'..='\u{125d}',
+    '\u{1260}'..='\u{1288}',
+    '\u{128a}'..='\u{128d}',
+    '\u{1290}'..='\u{12b0}',
+    '\u{12b2}'..='\u{12b5}',
+    '\u{12b8}'..='\u{12be}',
+    '\u{12c0}'..='\u{12c0}',
+    '\u{12c2}'..='\u{12c5}',
+    '\u{12c8}'..='\u{12d6}',
+    '\u{12d8}'..='\u{1310}',
+    '\u{1312}'..='\u{1315}',
+    '\u{1318}'..='\u{135a}',
+    '\u{135d}'..='\u{137c}',
+    '\u{1380}'..='\u{1399}',
+    '\u{2d80}'..='\u{2d96}',
+    '\u{2da0}'..='\u{2da6}',
+    '\u{2da8}'..='\u{2dae}',
+    '\u{2db0}'..='\u{2db6}',
+    '\u{2db8}'..='\u{2dbe}',
+    '\u{2dc0}'..='\u{2dc6}',
+    '\u{2dc8}'..='\u{2dce}',
+    '\u{2dd0}'..='\u{2dd6}',
+    '\u{2dd8}'..='\u{2dde}',
+    '\u{ab01}'..='\u{ab06}',
+    '\u{ab09}'..='\u{ab0e}',
+    '\u{ab11}'..='\u{ab16}',
+    '\u{ab20}'..='\u{ab26}',
+    '\u{ab28}'..='\u{ab2e}',
+    '\u{1e7e0}'..='\u{1e7e6}',
+    '\u{1e7e8}'..='\u{1e7eb}',
+    '\u{1e7ed}'..='\u{1e7ee}',
+    '\u{1e7f0}'..='\u{1e7fe}',
+];
+
+pub(crate) static GARAY: &[CharClassRange] = &[
+    '\u{10d40}'..='\u{10d65}',
+    '\u{10d69}'..='\u{10d85}',
+    '\u{10d8e}'..='\u{10d8f}',
+];
+
+pub(crate) static GEORGIAN: &[CharClassRange] = &[
+    '\u{10a0}'..='\u{10c5}',
+    '\u{10c7}'..='\u{10c7}',
+    '\u{10cd}'..='\u{10cd}',
+    '\u{10d0}'..='\u{10fa}',
+    '\u{10fc}'..='\u{10ff}',
+    '\u{1c90}'..='\u{1cba}',
+    '\u{1cbd}'..='\u{1cbf}',
+    '\u{2d00}'..='\u{2d25}',
+    '\u{2d27}'..='\u{2d27}',
+    '\u{2d2d}'..='\u{2d2d}',
+];
+
+pub(crate) static GLAGOLITIC: &[CharClassRange] = &[
+    '\u{2c00}'..='\u{2c5f}',
+    '\u{1e000}'..='\u{1e006}',
+    '\u{1e008}'..='\u{1e018}',
+    '\u{1e01b}'..='\u{1e021}',
+    '\u{1e023}'..='\u{1e024}',
+    '\u{1e026}'..='\u{1e02a}',
+];
+
+pub(crate) static GOTHIC: &[CharClassRange] = &[
+    '\u{10330}'..='\u{1034a}',
+];
+
+pub(crate) static GRANTHA: &[CharClassRange] = &[
+    '\u{11300}'..='\u{11303}',
+    '\u{11305}'..='\u{1130c}',
+    '\u{1130f}'..='\u{11310}',
+    '\u{11313}'..='\u{11328}',
+    '\u{1132a}'..='\u{11330}',
+    '\u{11332}'..='\u{11333}',
+    '\u{11335}'..='\u{11339}',
+    '\u{1133c}'..='\u{11344}',
+    '\u{11347}'..='\u{11348}',
+    '\u{1134b}'..='\u{1134d}',
+    '\u{11350}'..='\u{11350}',
+    '\u{11357}'..='\u{11357}',
+    '\u{1135d}'..='\u{11363}',
+    '\u{11366}'..='\u{1136c}',
+    '\u{11370}'..='\u{11374}',
+];
+
+pub(crate) static GREEK: &[CharClassRange] = &[
+    '\u{370}'..='\u{373}',
+    '\u{375}'..='\u{377}',
+    '\u{37a}'..='\u{37d}',
+    '\u{37f}'..='\u{37f}',
+    '\u{384}'..='\u{384}',
+    '\u{386}'..='\u{386}',
+    '\u{388}'..='\u{38a}',
+    '\u{38c}'..='\u{38c}',
+    '\u{38e}'..='\u{3a1}',
+    '\u{3a3}'..='\u{3e1}',
+    '\u{3f0}'..='\u{3ff}',
+    '\u{1d26}'..='\u{1d2a}',
+    '\u{1d5d}'..='\u{1d61}',
+    '\u{1d66}'..='\u{1d6a}',
+    '\u{1dbf}'..='\u{1dbf}',
+    '\u{1f00}'..='\u{1f15}',
+    '\u{1f18}'..='\u{1f1d}',
+    '\u{1f20}'..='\u{1f45}',
+    '\u{1f48}'..='\u{1f4d}',
+    '\u{1f50}'..='\u{1f57}',
+    '\u{1f59}'..='\u{1f59}',
+    '\u{1f5b}'..='\u{1f5b}',
+    '\u{1f5d}'..='\u{1f5d}',
+    '\u{1f5f}'..='\u{1f7d}',
+    '\u{1f80}'..='\u{1fb4}',
+    '\u{1fb6}'..='\u{1fc4}',
+    '\u{1fc6}'..='\u{1fd3}',
+    '\u{1fd6}'..='\u{1fdb}',
+    '\u{1fdd}'..='\u{1fef}',
+    '\u{1ff2}'..='\u{1ff4}',
+    '\u{1ff6}'..='\u{1ffe}',
+    '\u{2126}'..='\u{2126}',
+    '\u{ab65}'..='\u{ab65}',
+    '\u{10140}'..='\u{1018e}',
+    '\u{101a0}'..='\u{101a0}',
+    '\u{1d200}'..='\u{1d245}',
+];
+
+pub(crate) static GUJARATI: &[CharClassRange] = &[
+    '\u{a81}'..='\u{a83}',
+    '\u{a85}'..='\u{a8d}',
+    '\u{a8f}'..='\u{a91}',
+    '\u{a93}'..='\u{aa8}',
+    '\u{aaa}'..='\u{ab0}',
+    '\u{ab2}'..='\u{ab3}',
+    '\u{ab5}'..='\u{ab9}',
+    '\u{abc}'..='\u{ac5}',
+    '\u{ac7}'..='\u{ac9}',
+    '\u{acb}'..='\u{acd}',
+    '\u{ad0}'..='\u{ad0}',
+    '\u{ae0}'..='\u{ae3}',
+    '\u{ae6}'..='\u{af1}',
+    '\u{af9}'..='\u{aff}',
+];
+
+pub(crate) static GUNJALA_GONDI: &[CharClassRange] = &[
+    '\u{11d60}'..='\u{11d65}',
+    '\u{11d67}'..='\u{11d68}',
+    '\u{11d6a}'..='\u{11d8e}',
+    '\u{11d90}'..='\u{11d91}',
+    '\u{11d93}'..='\u{11d98}',
+    '\u{11da0}'..='\u{11da9}',
+];
+
+pub(crate) static GURMUKHI: &[CharClassRange] = &[
+    '\u{a01}'..='\u{a03}',
+    '\u{a05}'..='\u{a0a}',
+    '\u{a0f}'..='\u{a10}',
+    '\u{a13}'..='\u{a28}',
+    '\u{a2a}'..='\u{a30}',
+    '\u{a32}'..='\u{a33}',
+    '\u{a35}'..='\u{a36}',
+    '\u{a38}'..='\u{a39}',
+    '\u{a3c}'..='\u{a3c}',
+    '\u{a3e}'..='\u{a42}',
+    '\u{a47}'..='\u{a48}',
+    '\u{a4b}'..='\u{a4d}',
+    '\u{a51}'..='\u{a51}',
+    '\u{a59}'..='\u{a5c}',
+    '\u{a5e}'..='\u{a5e}',
+    '\u{a66}'..='\u{a76}',
+];
+
+pub(crate) static GURUNG_KHEMA: &[CharClassRange] = &[
+    '\u{16100}'..='\u{16139}',
+];
+
+pub(crate) static HAN: &[CharClassRange] = &[
+    '\u{2e80}'..='\u{2e99}',
+    '\u{2e9b}'..='\u{2ef3}',
+    '\u{2f00}'..='\u{2fd5}',
+    '\u{3005}'..='\u{3005}',
+    '\u{3007}'..='\u{3007}',
+    '\u{3021}'..='\u{3029}',
+    '\u{3038}'..='\u{303b}',
+    '\u{3400}'..='\u{4dbf}',
+    '\u{4e00}'..='\u{9fff}',
+    '\u{f900}'..='\u{fa6d}',
+    '\u{fa70}'..='\u{fad9}',
+    '\u{16fe2}'..='\u{16fe3}',
+    '\u{16ff0}'..='\u{16ff6}',
+    '\u{20000}'..='\u{2a6df}',
+    '\u{2a700}'..='\u{2b81d}',
+    '\u{2b820}'..='\u{2cead}',
+    '\u{2ceb0}'..='\u{2ebe0}',
+    '\u{2ebf0}'..='\u{2ee5d}',
+    '\u{2f800}'..='\u{2fa1d}',
+    '\u{30000}'..='\u{3134a}',
+    '\u{31350}'..='\u{33479}',
+];
+
+pub(crate) static HANGUL: &[CharClassRange] = &[
+    '\u{1100}'..='\u{11ff}',
+    '\u{302e}'..='\u{302f}',
+    '\u{3131}'..='\u{318e}',
+    '\u{3200}'..='\u{321e}',
+    '\u{3260}'..='\u{327e}',
+    '\u{a960}'..='\u{a97c}',
+    '\u{ac00}'..='\u{d7a3}',
+    '\u{d7b0}'..='\u{d7c6}',
+    '\u{d7cb}'..='\u{d7fb}',
+    '\u{ffa0}'..='\u{ffbe}',
+    '\u{ffc2}'..='\u{ffc7}',
+    '\u{ffca}'..='\u{ffcf}',
+    '\u{ffd2}'..='\u{ffd7}',
+    '\u{ffda}'..='\u{ffdc}',
+];
+
+pub(crate) static HANIFI_ROHINGYA: &[CharClassRange] = &[
+    '\u{10d00}'..='\u{10d27}',
+    '\u{10d30}'..='\u{10d39}',
+];
+
+pub(crate) static HANUNOO: &[CharClassRange] = &[
+    '\u{1720}'..='\u{1734}',
+];
+
+pub(crate) static HATRAN: &[CharClassRange] = &[
+    '\u{108e0}'..='\u{108f2}',
+    '\u{108f4}'..='\u{108f5}',
+    '\u{108fb}'..='\u{108ff}',
+];
+
+pub(crate) static HEBREW: &[CharClassRange] = &[
+    '\u{591}'..='\u{5c7}',
+    '\u{5d0}'..='\u{5ea}',
+    '\u{5ef}'..='\u{5f4}',
+    '\u{fb1d}'..='\u{fb36}',
+    '\u{fb38}'..='\u{fb3c}',
+    '\u{fb3e}'..='\u{fb3e}',
+    '\u{fb40}'..='\u{fb41}',
+    '\u{fb43}'..='\u{fb44}',
+    '\u{fb46}'..='\u{fb4f}',
+];
+
+pub(crate) static HIRAGANA: &[CharClassRange] = &[
+    '\u{3041}'..='\u{3096}',
+    '\u{309d}'..='\u{309f}',
+    '\u{1b001}'..='\u{1b11f}',
+    '\u{1b132}'..='\u{1b132}',
+    '\u{1b150}'..='\u{1b152}',
+    '\u{1f200}'..='\u{1f200}',
+];
+
+pub(crate) static IMPERIAL_ARAMAIC: &[CharClassRange] = &[
+    '\u{10840}'..='\u{10855}',
+    '\u{10857}'..='\u{1085f}',
+];
+
+pub(crate) static INHERITED: &[CharClassRange] = &[
+    '\u{300}'..='\u{36f}',
+    '\u{485}'..='\u{486}',
+    '\u{64b}'..='\u{655}',
+    '\u{670}'..='\u{670}',
+    '\u{951}'..='\u{954}',
+    '\u{1ab0}'..='\u{1add}',
+    '\u{1ae0}'..='\u{1aeb}',
+    '\u{1cd0}'..='\u{1cd2}',
+    '\u{1cd4}'..='\u{1ce0}',
+    '\u{1ce2}'..='\u{1ce8}',
+    '\u{1ced}'..='\u{1ced}',
+    '\u{1cf4}'..='\u{1cf4}',
+    '\u{1cf8}'..='\u{1cf9}',
+    '\u{1dc0}'..='\u{1dff}',
+    '\u{200c}'..='\u{200d}',
+    '\u{20d0}'..='\u{20f0}',
+    '\u{302a}'..='\u{302d}',
+    '\u{3099}'..='\u{309a}',
+    '\u{fe00}'..='\u{fe0f}',
+    '\u{fe20}'..='\u{fe2d}',
+    '\u{101fd}'..='\u{101fd}',
+    '\u{102e0}'..='\u{102e0}',
+    '\u{1133b}'..='\u{1133b}',
+    '\u{1cf00}'..='\u{1cf2d}',
+    '\u{1cf30}'..='\u{1cf46}',
+    '\u{1d167}'..='\u{1d169}',
+    '\u{1d17b}'..='\u{1d182}',
+    '\u{1d185}'..='\u{1d18b}',
+    '\u{1d1aa}'..='\u{1d1ad}',
+    '\u{e0100}'..='\u{e01ef}',
+];
+
+pub(crate) static INSCRIPTIONAL_PAHLAVI: &[CharClassRange] = &[
+    '\u{10b60}'..='\u{10b72}',
+    '\u{10b78}'..='\u{10b7f}',
+];
+
+pub(crate) static INSCRIPTIONAL_PARTHIAN: &[CharClassRange] = &[
+    '\u{10b40}'..='\u{10b55}',
+    '\u{10b58}'..='\u{10b5f}',
+];
+
+pub(crate) static JAVANESE: &[CharClassRange] = &[
+    '\u{a980}'..='\u{a9cd}',
+    '\u{a9d0}'..='\u{a9d9}',
+    '\u{a9de}'..='\u{a9df}',
+];
+
+pub(crate) static KAITHI: &[CharClassRange] = &[
+    '\u{11080}'..='\u{110c2}',
+    '\u{110cd}'..='\u{110cd}',
+];
+
+pub(crate) static KANNADA: &[CharClassRange] = &[
+    '\u{c80}'..='\u{c8c}',
+    '\u{c8e}'..='\u{c90}',
+    '\u{c92}'..='\u{ca8}',
+    '\u{caa}'..='\u{cb3}',
+    '\u{cb5}'..='\u{cb9}',
+    '\u{cbc}'..='\u{cc4}',
+    '\u{cc6}'..='\u{cc8}',
+    '\u{cca}'..='\u{ccd}',
+    '\u{cd5}'..='\u{cd6}',
+    '\u{cdc}'..='\u{cde}',
+    '\u{ce0}'..='\u{ce3}',
+    '\u{ce6}'..='\u{cef}',
+    '\u{cf1}'..='\u{cf3}',
+];
+
+pub(crate) static KATAKANA: &[CharClassRange] = &[
+    '\u{30a1}'..='\u{30fa}',
+    '\u{30fd}'..='\u{30ff}',
+    '\u{31f0}'..='\u{31ff}',
+    '\u{32d0}'..='\u{32fe}',
+    '\u{3300}'..='\u{3357}',
+    '\u{ff66}'..='\u{ff6f}',
+    '\u{ff71}'..='\u{ff9d}',
+    '\u{1aff0}'..='\u{1aff3}',
+    '\u{1aff5}'..='\u{1affb}',
+    '\u{1affd}'..='\u{1affe}',
+    '\u{1b000}'..='\u{1b000}',
+    '\u{1b120}'..='\u{1b122}',
+    '\u{1b155}'..='\u{1b155}',
+    '\u{1b164}'..='\u{1b167}',
+];
+
+pub(crate) static KAWI: &[CharClassRange] = &[
+    '\u{11f00}'..='\u{11f10}',
+    '\u{11f12}'..='\u{11f3a}',
+    '\u{11f3e}'..='\u{11f5a}',
+];
+
+pub(crate) static KAYAH_LI: &[CharClassRange] = &[
+    '\u{a900}'..='\u{a92d}',
+    '\u{a92f}'..='\u{a92f}',
+];
+
+pub(crate) static KHAROSHTHI: &[CharClassRange] = &[
+    '\u{10a00}'..='\u{10a03}',
+    '\u{10a05}'..='\u{10a06}',
+    '\u{10a0c}'..='\u{10a13}',
+    '\u{10a15}'..='\u{10a17}',
+    '\u{10a19}'..='\u{10a35}',
+    '\u{10a38}'..='\u{10a3a}',
+    '\u{10a3f}'..='\u{10a48}',
+    '\u{10a50}'..='\u{10a58}',
+];
+
+pub(crate) static KHITAN_SMALL_SCRIPT: &[CharClassRange] = &[
+    '\u{16fe4}'..='\u{16fe4}',
+    '\u{18b00}'..='\u{18cd5}',
+    '\u{18cff}'..='\u{18cff}',
+];
+
+pub(crate) static KHMER: &[CharClassRange] = &[
+    '\u{1780}'..='\u{17dd}',
+    '\u{17e0}'..='\u{17e9}',
+    '\u{17f0}'..='\u{17f9}',
+    '\u{19e0}'..='\u{19ff}',
+];
+
+pub(crate) static KHOJKI: &[CharClassRange] = &[
+    '\u{11200}'..='\u{11211}',
+    '\u{11213}'..='\u{11241}',
+];
+
+pub(crate) static KHUDAWADI: &[CharClassRange] = &[
+    '\u{112b0}'..='\u{112ea}',
+    '\u{112f0}'..='\u{112f9}',
+];
+
+pub(crate) static KIRAT_RAI: &[CharClassRange] = &[
+    '\u{16d40}'..='\u{16d79}',
+];
+
+pub(crate) static LAO: &[CharClassRange] = &[
+    '\u{e81}'..='\u{e82}',
+    '\u{e84}'..='\u{e84}',
+    '\u{e86}'..='\u{e8a}',
+    '\u{e8c}'..='\u{ea3}',
+    '\u{ea5}'..='\u{ea5}',
+    '\u{ea7}'..='\u{ebd}',
+    '\u{ec0}'..='\u{ec4}',
+    '\u{ec6}'..='\u{ec6}',
+    '\u{ec8}'..='\u{ece}',
+    '\u{ed0}'..='\u{ed9}',
+    '\u{edc}'..='\u{edf}',
+];
+
+pub(crate) static LATIN: &[CharClassRange] = &[
+    '\u{41}'..='\u{5a}',
+    '\u{61}'..='\u{7a}',
+    '\u{aa}'..='\u{aa}',
+    '\u{ba}'..='\u{ba}',
+    '\u{c0}'..='\u{d6}',
+    '\u{d8}'..='\u{f6}',
+    '\u{f8}'..='\u{2b8}',
+    '\u{2e0}'..='\u{2e4}',
+    '\u{1d00}'..='\u{1d25}',
+    '\u{1d2c}'..='\u{1d5c}',
+    '\u{1d62}'..='\u{1d65}',
+    '\u{1d6b}'..='\u{1d77}',
+    '\u{1d79}'..='\u{1dbe}',
+    '\u{1e00}'..='\u{1eff}',
+    '\u{2071}'..='\u{2071}',
+    '\u{207f}'..='\u{207f}',
+    '\u{2090}'..='\u{209c}',
+    '\u{212a}'..='\u{212b}',
+    '\u{2132}'..='\u{2132}',
+    '\u{214e}'..='\u{214e}',
+    '\u{2160}'..='\u{2188}',
+    '\u{2c60}'..='\u{2c7f}',
+    '\u{a722}'..='\u{a787}',
+    '\u{a78b}'..='\u{a7dc}',
+    '\u{a7f1}'..='\u{a7ff}',
+    '\u{ab30}'..='\u{ab5a}',
+    '\u{ab5c}'..='\u{ab64}',
+    '\u{ab66}'..='\u{ab69}',
+    '\u{fb00}'..='\u{fb06}',
+    '\u{ff21}'..='\u{ff3a}',
+    '\u{ff41}'..='\u{ff5a}',
+    '\u{10780}'..='\u{10785}',
+    '\u{10787}'..='\u{107b0}',
+    '\u{107b2}'..='\u{107ba}',
+    '\u{1df00}'..='\u{1df1e}',
+    '\u{1df25}'..='\u{1df2a}',
+];
+
+pub(crate) static LEPCHA: &[CharClassRange] = &[
+    '\u{1c00}'..='\u{1c37}',
+    '\u{1c3b}'..='\u{1c49}',
+    '\u{1c4d}'..='\u{1c4f}',
+];
+
+pub(crate) static LIMBU: &[CharClassRange] = &[
+    '\u{1900}'..='\u{191e}',
+    '\u{1920}'..='\u{192b}',
+    '\u{1930}'..='\u{193b}',
+    '\u{1940}'..='\u{1940}',
+    '\u{1944}'..='\u{194f}',
+];
+
+pub(crate) static LINEAR_A: &[CharClassRange] = &[
+    '\u{10600}'..='\u{10736}',
+    '\u{10740}'..='\u{10755}',
+    '\u{10760}'..='\u{10767}',
+];
+
+pub(crate) static LINEAR_B: &[CharClassRange] = &[
+    '\u{10000}'..='\u{1000b}',
+    '\u{1000d}'..='\u{10026}',
+    '\u{10028}'..='\u{1003a}',
+    '\u{1003c}'..='\u{1003d}',
+    '\u{1003f}'..='\u{1004d}',
+    '\u{10050}'..='\u{1005d}',
+    '\u{10080}'..='\u{100fa}',
+];
+
+pub(crate) static LISU: &[CharClassRange] = &[
+    '\u{a4d0}'..='\u{a4ff}',
+    '\u{11fb0}'..='\u{11fb0}',
+];
+
+pub(crate) static LYCIAN: &[CharClassRange] = &[
+    '\u{10280}'..='\u{1029c}',
+];
+
+pub(crate) static LYDIAN: &[CharClassRange] = &[
+    '\u{10920}'..='\u{10939}',
+    '\u{1093f}'..='\u{1093f}',
+];
+
+pub(crate) static MAHAJANI: &[CharClassRange] = &[
+    '\u{11150}'..='\u{11176}',
+];
+
+pub(crate) static MAKASAR: &[CharClassRange] = &[
+    '\u{11ee0}'..='\u{11ef8}',
+];
+
+pub(crate) static MALAYALAM: &[CharClassRange] = &[
+    '\u{d00}'..='\u{d0c}',
+    '\u{d0e}'..='\u{d10}',
+    '\u{d12}'..='\u{d44}',
+    '\u{d46}'..='\u{d48}',
+    '\u{d4a}'..='\u{d4f}',
+    '\u{d54}'..='\u{d63}',
+    '\u{d66}'..='\u{d7f}',
+];
+
+pub(crate) static MANDAIC: &[CharClassRange] = &[
+    '\u{840}'..='\u{85b}',
+    '\u{85e}'..='\u{85e}',
+];
+
+pub(crate) static MANICHAEAN: &[CharClassRange] = &[
+    '\u{10ac0}'..='\u{10ae6}',
+    '\u{10aeb}'..='\u{10af6}',
+];
+
+pub(crate) static MARCHEN: &[CharClassRange] = &[
+    '\u{11c70}'..='\u{11c8f}',
+    '\u{11c92}'..='\u{11ca7}',
+    '\u{11ca9}'..='\u{11cb6}',
+];
+
+pub(crate) static MASARAM_GONDI: &[CharClassRange] = &[
+    '\u{11d00}'..='\u{11d06}',
+    '\u{11d08}'..='\u{11d09}',
+    '\u{11d0b}'..='\u{11d36}',
+    '\u{11d3a}'..='\u{11d3a}',
+    '\u{11d3c}'..='\u{11d3d}',
+    '\u{11d3f}'..='\u{11d47}',
+    '\u{11d50}'..='\u{11d59}',
+];
+
+pub(crate) static MEDEFAIDRIN: &[CharClassRange] = &[
+    '\u{16e40}'..='\u{16e9a}',
+];
+
+pub(crate) static MEETEI_MAYEK: &[CharClassRange] = &[
+    '\u{aae0}'..='\u{aaf6}',
+    '\u{abc0}'..='\u{abed}',
+    '\u{abf0}'..='\u{abf9}',
+];
+
+pub(crate) static MENDE_KIKAKUI: &[CharClassRange] = &[
+    '\u{1e800}'..='\u{1e8c4}',
+    '\u{1e8c7}'..='\u{1e8d6}',
+];
+
+pub(crate) static MEROITIC_CURSIVE: &[CharClassRange] = &[
+    '\u{109a0}'..='\u{109b7}',
+    '\u{109bc}'..='\u{109cf}',
+    '\u{109d2}'..='\u{109ff}',
+];
+
+pub(crate) static MEROITIC_HIEROGLYPHS: &[CharClassRange] = &[
+    '\u{10980}'..='\u{1099f}',
+];
+
+pub(crate) static MIAO: &[CharClassRange] = &[
+    '\u{16f00}'..='\u{16f4a}',
+    '\u{16f4f}'..='\u{16f87}',
+    '\u{16f8f}'..='\u{16f9f}',
+];
+
+pub(crate) static MODI: &[CharClassRange] = &[
+    '\u{11600}'..='\u{11644}',
+    '\u{11650}'..='\u{11659}',
+];
+
+pub(crate) static MONGOLIAN: &[CharClassRange] = &[
+    '\u{1800}'..='\u{1801}',
+    '\u{1804}'..='\u{1804}',
+    '\u{1806}'..='\u{1819}',
+    '\u{1820}'..='\u{1878}',
+    '\u{1880}'..='\u{18aa}',
+    '\u{11660}'..='\u{1166c}',
+];
+
+pub(crate) static MRO: &[CharClassRange] = &[
+    '\u{16a40}'..='\u{16a5e}',
+    '\u{16a60}'..='\u{16a69}',
+    '\u{16a6e}'..='\u{16a6f}',
+];
+
+pub(crate) static MULTANI: &[CharClassRange] = &[
+    '\u{11280}'..='\u{11286}',
+    '\u{11288}'..='\u{11288}',
+    '\u{1128a}'..='\u{1128d}',
+    '\u{1128f}'..='\u{1129d}',
+    '\u{1129f}'..='\u{112a9}',
+];
+
+pub(crate) static MYANMAR: &[CharClassRange] = &[
+    '\u{1000}'..='\u{109f}',
+    '\u{a9e0}'..='\u{a9fe}',
+    '\u{aa60}'..='\u{aa7f}',
+    '\u{116d0}'..='\u{116e3}',
+];
+
+pub(crate) static NABATAEAN: &[CharClassRange] = &[
+    '\u{10880}'..='\u{1089e}',
+    '\u{108a7}'..='\u{108af}',
+];
+
+pub(crate) static NAG_MUNDARI: &[CharClassRange] = &[
+    '\u{1e4d0}'..='\u{1e4f9}',
+];
+
+pub(crate) static NANDINAGARI: &[CharClassRange] = &[
+    '\u{119a0}'..='\u{119a7}',
+    '\u{119aa}'..='\u{119d7}',
+    '\u{119da}'..='\u{119e4}',
+];
+
+pub(crate) static NEW_TAI_LUE: &[CharClassRange] = &[
+    '\u{1980}'..='\u{19ab}',
+    '\u{19b0}'..='\u{19c9}',
+    '\u{19d0}'..='\u{19da}',
+    '\u{19de}'..='\u{19df}',
+];
+
+pub(crate) static NEWA: &[CharClassRange] = &[
+    '\u{11400}'..='\u{1145b}',
+    '\u{1145d}'..='\u{11461}',
+];
+
+pub(crate) static NKO: &[CharClassRange] = &[
+    '\u{7c0}'..='\u{7fa}',
+    '\u{7fd}'..='\u{7ff}',
+];
+
+pub(crate) static NUSHU: &[CharClassRange] = &[
+    '\u{16fe1}'..='\u{16fe1}',
+    '\u{1b170}'..='\u{1b2fb}',
+];
+
+pub(crate) static NYIAKENG_PUACHUE_HMONG: &[CharClassRange] = &[
+    '\u{1e100}'..='\u{1e12c}',
+    '\u{1e130}'..='\u{1e13d}',
+    '\u{1e140}'..='\u{1e149}',
+    '\u{1e14e}'..='\u{1e14f}',
+];
+
+pub(crate) static OGHAM: &[CharClassRange] = &[
+    '\u{1680}'..='\u{169c}',
+];
+
+pub(crate) static OL_CHIKI: &[CharClassRange] = &[
+    '\u{1c50}'..='\u{1c7f}',
+];
+
+pub(crate) static OL_ONAL: &[CharClassRange] = &[
+    '\u{1e5d0}'..='\u{1e5fa}',
+    '\u{1e5ff}'..='\u{1e5ff}',
+];
+
+pub(crate) static OLD_HUNGARIAN: &[CharClassRange] = &[
+    '\u{10c80}'..='\u{10cb2}',
+    '\u{10cc0}'..='\u{10cf2}',
+    '\u{10cfa}'..='\u{10cff}',
+];
+
+pub(crate) static OLD_ITALIC: &[CharClassRange] = &[
+    '\u{10300}'..='\u{10323}',
+    '\u{1032d}'..='\u{1032f}',
+];
+
+pub(crate) static OLD_NORTH_ARABIAN: &[CharClassRange] = &[
+    '\u{10a80}'..='\u{10a9f}',
+];
+
+pub(crate) static OLD_PERMIC: &[CharClassRange] = &[
+    '\u{10350}'..='\u{1037a}',
+];
+
+pub(crate) static OLD_PERSIAN: &[CharClassRange] = &[
+    '\u{103a0}'..='\u{103c3}',
+    '\u{103c8}'..='\u{103d5}',
+];
+
+pub(crate) static OLD_SOGDIAN: &[CharClassRange] = &[
+    '\u{10f00}'..='\u{10f27}',
+];
+
+pub(crate) static OLD_SOUTH_ARABIAN: &[CharClassRange] = &[
+    '\u{10a60}'..='\u{10a7f}',
+];
+
+pub(crate) static OLD_TURKIC: &[CharClassRange] = &[
+    '\u{10c00}'..='\u{10c48}',
+];
+
+pub(crate) static OLD_UYGHUR: &[CharClassRange] = &[
+    '\u{10f70}'..='\u{10f89}',
+];
+
+pub(crate) static ORIYA: &[CharClassRange] = &[
+    '\u{b01}'..='\u{b03}',
+    '\u{b05}'..='\u{b0c}',
+    '\u{b0f}'..='\u{b10}',
+    '\u{b13}'..='\u{b28}',
+    '\u{b2a}'..='\u{b30}',
+    '\u{b32}'..='\u{b33}',
+    '\u{b35}'..='\u{b39}',
+    '\u{b3c}'..='\u{b44}',
+    '\u{b47}'..='\u{b48}',
+    '\u{b4b}'..='\u{b4d}',
+    '\u{b55}'..='\u{b57}',
+    '\u{b5c}'..='\u{b5d}',
+    '\u{b5f}'..='\u{b63}',
+    '\u{b66}'..='\u{b77}',
+];
+
+pub(crate) static OSAGE: &[CharClassRange] = &[
+    '\u{104b0}'..='\u{104d3}',
+    '\u{104d8}'..='\u{104fb}',
+];
+
+pub(crate) static OSMANYA: &[CharClassRange] = &[
+    '\u{10480}'..='\u{1049d}',
+    '\u{104a0}'..='\u{104a9}',
+];
+
+pub(crate) static PAHAWH_HMONG: &[CharClassRange] = &[
+    '\u{16b00}'..='\u{16b45}',
+    '\u{16b50}'..='\u{16b59}',
+    '\u{16b5b}'..='\u{16b61}',
+    '\u{16b63}'..='\u{16b77}',
+    '\u{16b7d}'..='\u{16b8f}',
+];
+
+pub(crate) static PALMYRENE: &[CharClassRange] = &[
+    '\u{10860}'..='\u{1087f}',
+];
+
+pub(crate) static PAU_CIN_HAU: &[CharClassRange] = &[
+    '\u{11ac0}'..='\u{11af8}',
+];
+
+pub(crate) static PHAGS_PA: &[CharClassRange] = &[
+    '\u{a840}'..='\u{a877}',
+];
+
+pub(crate) static PHOENICIAN: &[CharClassRange] = &[
+    '\u{10900}'..='\u{1091b}',
+    '\u{1091f}'..='\u{1091f}',
+];
+
+pub(crate) static PSALTER_PAHLAVI: &[CharClassRange] = &[
+    '\u{10b80}'..='\u{10b91}',
+    '\u{10b99}'..='\u{10b9c}',
+    '\u{10ba9}'..='\u{10baf}',
+];
+
+pub(crate) static REJANG: &[CharClassRange] = &[
+    '\u{a930}'..='\u{a953}',
+    '\u{a95f}'..='\u{a95f}',
+];
+
+pub(crate) static RUNIC: &[CharClassRange] = &[
+    '\u{16a0}'..='\u{16ea}',
+    '\u{16ee}'..='\u{16f8}',
+];
+
+pub(crate) static SAMARITAN: &[CharClassRange] = &[
+    '\u{800}'..='\u{82d}',
+    '\u{830}'..='\u{83e}',
+];
+
+pub(crate) static SAURASHTRA: &[CharClassRange] = &[
+    '\u{a880}'..='\u{a8c5}',
+    '\u{a8ce}'..='\u{a8d9}',
+];
+
+pub(crate) static SHARADA: &[CharClassRange] = &[
+    '\u{11180}'..='\u{111df}',
+    '\u{11b60}'..='\u{11b67}',
+];
+
+pub(crate) static SHAVIAN: &[CharClassRange] = &[
+    '\u{10450}'..='\u{1047f}',
+];
+
+pub(crate) static SIDDHAM: &[CharClassRange] = &[
+    '\u{11580}'..='\u{115b5}',
+    '\u{115b8}'..='\u{115dd}',
+];
+
+pub(crate) static SIDETIC: &[CharClassRange] = &[
+    '\u{10940}'..='\u{10959}',
+];
+
+pub(crate) static SIGNWRITING: &[CharClassRange] = &[
+    '\u{1d800}'..='\u{1da8b}',
+    '\u{1da9b}'..='\u{1da9f}',
+    '\u{1daa1}'..='\u{1daaf}',
+];
+
+pub(crate) static SINHALA: &[CharClassRange] = &[
+    '\u{d81}'..='\u{d83}',
+    '\u{d85}'..='\u{d96}',
+    '\u{d9a}'..='\u{db1}',
+    '\u{db3}'..='\u{dbb}',
+    '\u{dbd}'..='\u{dbd}',
+    '\u{dc0}'..='\u{dc6}',
+    '\u{dca}'..='\u{dca}',
+    '\u{dcf}'..='\u{dd4}',
+    '\u{dd6}'..='\u{dd6}',
+    '\u{dd8}'..='\u{ddf}',
+    '\u{de6}'..='\u{def}',
+    '\u{df2}'..='\u{df4}',
+    '\u{111e1}'..='\u{111f4}',
+];
+
+pub(crate) static SOGDIAN: &[CharClassRange] = &[
+    '\u{10f30}'..='\u{10f59}',
+];
+
+pub(crate) static SORA_SOMPENG: &[CharClassRange] = &[
+    '\u{110d0}'..='\u{110e8}',
+    '\u{110f0}'..='\u{110f9}',
+];
+
+pub(crate) static SOYOMBO: &[CharClassRange] = &[
+    '\u{11a50}'..='\u{11aa2}',
+];
+
+pub(crate) static SUNDANESE: &[CharClassRange] = &[
+    '\u{1b80}'..='\u{1bbf}',
+    '\u{1cc0}'..='\u{1cc7}',
+];
+
+pub(crate) static SUNUWAR: &[CharClassRange] = &[
+    '\u{11bc0}'..='\u{11be1}',
+    '\u{11bf0}'..='\u{11bf9}',
+];
+
+pub(crate) static SYLOTI_NAGRI: &[CharClassRange] = &[
+    '\u{a800}'..='\u{a82c}',
+];
+
+pub(crate) static SYRIAC: &[CharClassRange] = &[
+    '\u{700}'..='\u{70d}',
+    '\u{70f}'..='\u{74a}',
+    '\u{74d}'..='\u{74f}',
+    '\u{860}'..='\u{86a}',
+];
+
+pub(crate) static TAGALOG: &[CharClassRange] = &[
+    '\u{1700}'..='\u{1715}',
+    '\u{171f}'..='\u{171f}',
+];
+
+pub(crate) static TAGBANWA: &[CharClassRange] = &[
+    '\u{1760}'..='\u{176c}',
+    '\u{176e}'..='\u{1770}',
+    '\u{1772}'..='\u{1773}',
+];
+
+pub(crate) static TAI_LE: &[CharClassRange] = &[
+    '\u{1950}'..='\u{196d}',
+    '\u{1970}'..='\u{1974}',
+];
+
+pub(crate) static TAI_THAM: &[CharClassRange] = &[
+    '\u{1a20}'..='\u{1a5e}',
+    '\u{1a60}'..='\u{1a7c}',
+    '\u{1a7f}'..='\u{1a89}',
+    '\u{1a90}'..='\u{1a99}',
+    '\u{1aa0}'..='\u{1aad}',
+];
+
+pub(crate) static TAI_VIET: &[CharClassRange] = &[
+    '\u{aa80}'..='\u{aac2}',
+    '\u{aadb}'..='\u{aadf}',
+];
+
+pub(crate) static TAI_YO: &[CharClassRange] = &[
+    '\u{1e6c0}'..='\u{1e6de}',
+    '\u{1e6e0}'..='\u{1e6f5}',
+    '\u{1e6fe}'..='\u{1e6ff}',
+];
+
+pub(crate) static TAKRI: &[CharClassRange] = &[
+    '\u{11680}'..='\u{116b9}',
+    '\u{116c0}'..='\u{116c9}',
+];
+
+pub(crate) static TAMIL: &[CharClassRange] = &[
+    '\u{b82}'..='\u{b83}',
+    '\u{b85}'..='\u{b8a}',
+    '\u{b8e}'..='\u{b90}',
+    '\u{b92}'..='\u{b95}',
+    '\u{b99}'..='\u{b9a}',
+    '\u{b9c}'..='\u{b9c}',
+    '\u{b9e}'..='\u{b9f}',
+    '\u{ba3}'..='\u{ba4}',
+    '\u{ba8}'..='\u{baa}',
+    '\u{bae}'..='\u{bb9}',
+    '\u{bbe}'..='\u{bc2}',
+    '\u{bc6}'..='\u{bc8}',
+    '\u{bca}'..='\u{bcd}',
+    '\u{bd0}'..='\u{bd0}',
+    '\u{bd7}'..='\u{bd7}',
+    '\u{be6}'..='\u{bfa}',
+    '\u{11fc0}'..='\u{11ff1}',
+    '\u{11fff}'..='\u{11fff}',
+];
+
+pub(crate) static TANGSA: &[CharClassRange] = &[
+    '\u{16a70}'..='\u{16abe}',
+    '\u{16ac0}'..='\u{16ac9}',
+];
+
+pub(crate) static TANGUT: &[CharClassRange] = &[
+    '\u{16fe0}'..='\u{16fe0}',
+    '\u{17000}'..='\u{18aff}',
+    '\u{18d00}'..='\u{18d1e}',
+    '\u{18d80}'..='\u{18df2}',
+];
+
+pub(crate) static TELUGU: &[CharClassRange] = &[
+    '\u{c00}'..='\u{c0c}',
+    '\u{c0e}'..='\u{c10}',
+    '\u{c12}'..='\u{c28}',
+    '\u{c2a}'..='\u{c39}',
+    '\u{c3c}'..='\u{c44}',
+    '\u{c46}'..='\u{c48}',
+    '\u{c4a}'..='\u{c4d}',
+    '\u{c55}'..='\u{c56}',
+    '\u{c58}'..='\u{c5a}',
+    '\u{c5c}'..='\u{c5d}',
+    '\u{c60}'..='\u{c63}',
+    '\u{c66}'..='\u{c6f}',
+    '\u{c77}'..='\u{c7f}',
+];
+
+pub(crate) static THAANA: &[CharClassRange] = &[
+    '\u{780}'..='\u{7b1}',
+];
+
+pub(crate) static THAI: &[CharClassRange] = &[
+    '\u{e01}'..='\u{e3a}',
+    '\u{e40}'..='\u{e5b}',
+];
+
+pub(crate) static TIBETAN: &[CharClassRange] = &[
+    '\u{f00}'..='\u{f47}',
+    '\u{f49}'..='\u{f6c}',
+    '\u{f71}'..='\u{f97}',
+    '\u{f99}'..='\u{fbc}',
+    '\u{fbe}'..='\u{fcc}',
+    '\u{fce}'..='\u{fd4}',
+    '\u{fd9}'..='\u{fda}',
+];
+
+pub(crate) static TIFINAGH: &[CharClassRange] = &[
+    '\u{2d30}'..='\u{2d67}',
+    '\u{2d6f}'..='\u{2d70}',
+    '\u{2d7f}'..='\u{2d7f}',
+];
+
+pub(crate) static TIRHUTA: &[CharClassRange] = &[
+    '\u{11480}'..='\u{114c7}',
+    '\u{114d0}'..='\u{114d9}',
+];
+
+pub(crate) static TODHRI: &[CharClassRange] = &[
+    '\u{105c0}'..='\u{105f3}',
+];
+
+pub(crate) static TOLONG_SIKI: &[CharClassRange] = &[
+    '\u{11db0}'..='\u{11ddb}',
+    '\u{11de0}'..='\u{11de9}',
+];
+
+pub(crate) static TOTO: &[CharClassRange] = &[
+    '\u{1e290}'..='\u{1e2ae}',
+];
+
+pub(crate) static TULU_TIGALARI: &[CharClassRange] = &[
+    '\u{11380}'..='\u{11389}',
+    '\u{1138b}'..='\u{1138b}',
+    '\u{1138e}'..='\u{1138e}',
+    '\u{11390}'..='\u{113b5}',
+    '\u{113b7}'..='\u{113c0}',
+    '\u{113c2}'..='\u{113c2}',
+    '\u{113c5}'..='\u{113c5}',
+    '\u{113c7}'..='\u{113ca}',
+    '\u{113cc}'..='\u{113d5}',
+    '\u{113d7}'..='\u{113d8}',
+    '\u{113e1}'..='\u{113e2}',
+];
+
+pub(crate) static UGARITIC: &[CharClassRange] = &[
+    '\u{10380}'..='\u{1039d}',
+    '\u{1039f}'..='\u{1039f}',
+];
+
+pub(crate) static VAI: &[CharClassRange] = &[
+    '\u{a500}'..='\u{a62b}',
+];
+
+pub(crate) static VITHKUQI: &[CharClassRange] = &[
+    '\u{10570}'..='\u{1057a}',
+    '\u{1057c}'..='\u{1058a}',
+    '\u{1058c}'..='\u{10592}',
+    '\u{10594}'..='\u{10595}',
+    '\u{10597}'..='\u{105a1}',
+    '\u{105a3}'..='\u{105b1}',
+    '\u{105b3}'..='\u{105b9}',
+    '\u{105bb}'..='\u{105bc}',
+];
+
+pub(crate) static WANCHO: &[CharClassRange] = &[
+    '\u{1e2c0}'..='\u{1e2f9}',
+    '\u{1e2ff}'..='\u{1e2ff}',
+];
+
+pub(crate) static WARANG_CITI: &[CharClassRange] = &[
+    '\u{118a0}'..='\u{118f2}',
+    '\u{118ff}'..='\u{118ff}',
+];
+
+pub(crate) static YEZIDI: &[CharClassRange] = &[
+    '\u{10e80}'..='\u{10ea9}',
+    '\u{10eab}'..='\u{10ead}',
+    '\u{10eb0}'..='\u{10eb1}',
+];
+
+pub(crate) static YI: &[CharClassRange] = &[
+    '\u{a000}'..='\u{a48c}',
+    '\u{a490}'..='\u{a4c6}',
+];
+
+pub(crate) static ZANABAZAR_SQUARE: &[CharClassRange] = &[
+    '\u{11a00}'..='\u{11a47}',
+];
+
+pub(crate) static SCRIPTS: &[(&str, &[CharClassRange])] = &[
+    ("Adlam", ADLAM),
+    ("Ahom", AHOM),
+    ("Anatolian_Hieroglyphs", ANATOLIAN_HIEROGLYPHS),
+    ("Arabic", ARABIC),
+    ("Armenian", ARMENIAN),
+    ("Avestan", AVESTAN),
+    ("Balinese", BALINESE),
+    ("Bamum", BAMUM),
+    ("Bassa_Vah", BASSA_VAH),
+    ("Batak", BATAK),
+    ("Bengali", BENGALI),
+    ("Beria_Erfe", BERIA_ERFE),
+    ("Bhaiksuki", BHAIKSUKI),
+    ("Bopomofo", BOPOMOFO),
+    ("Brahmi", BRAHMI),
+    ("Braille", BRAILLE),
+    ("Buginese", BUGINESE),
+    ("Buhid", BUHID),
+    ("Canadian_Aboriginal", CANADIAN_ABORIGINAL),
+    ("Carian", CARIAN),
+    ("Caucasian_Albanian", CAUCASIAN_ALBANIAN),
+    ("Chakma", CHAKMA),
+    ("Cham", CHAM),
+    ("Cherokee", CHEROKEE),
+    ("Chorasmian", CHORASMIAN),
+    ("Common", COMMON),
+    ("Coptic", COPTIC),
+    ("Cuneiform", CUNEIFORM),
+    ("Cypriot", CYPRIOT),
+    ("Cypro_Minoan", CYPRO_MINOAN),
+    ("Cyrillic", CYRILLIC),
+    ("Deseret", DESERET),
+    ("Devanagari", DEVANAGARI),
+    ("Dives_Akuru", DIVES_AKURU),
+    ("Dogra", DOGRA),
+    ("Duployan", DUPLOYAN),
+    ("Egyptian_Hieroglyphs", EGYPTIAN_HIEROGLYPHS),
+    ("Elbasan", ELBASAN),
+    ("Elymaic", ELYMAIC),
+    ("Ethiopic", ETHIOPIC),
+    ("Garay", GARAY),
+    ("Georgian", GEORGIAN),
+    ("Glagolitic", GLAGOLITIC),
+    ("Gothic", GOTHIC),
+    ("Grantha", GRANTHA),
+    ("Greek", GREEK),
+    ("Gujarati", GUJARATI),
+    ("Gunjala_Gondi", GUNJALA_GONDI),
+    ("Gurmukhi", GURMUKHI),
+    ("Gurung_Khema", GURUNG_KHEMA),
+    ("Han", HAN),
+    ("Hangul", HANGUL),
+    ("Hanifi_Rohingya", HANIFI_ROHINGYA),
+    ("Hanunoo", HANUNOO),
+    ("Hatran", HATRAN),
+    ("Hebrew", HEBREW),
+    ("Hiragana", HIRAGANA),
+    ("Imperial_Aramaic", IMPERIAL_ARAMAIC),
+    ("Inherited", INHERITED),
+    ("Inscriptional_Pahlavi", INSCRIPTIONAL_PAHLAVI),
+    ("Inscriptional_Parthian", INSCRIPTIONAL_PARTHIAN),
+    ("Javanese", JAVANESE),
+    ("Kaithi", KAITHI),
+    ("Kannada", KANNADA),
+    ("Katakana", KATAKANA),
+    ("Kawi", KAWI),
+    ("Kayah_Li", KAYAH_LI),
+    ("Kharoshthi", KHAROSHTHI),
+    ("Khitan_Small_Script", KHITAN_SMALL_SCRIPT),
+    ("Khmer", KHMER),
+    ("Khojki", KHOJKI),
+    ("Khudawadi", KHUDAWADI),
+    ("Kirat_Rai", KIRAT_RAI),
+    ("Lao", LAO),
+    ("Latin", LATIN),
+    ("Lepcha", LEPCHA),
+    ("Limbu", LIMBU),
+    ("Linear_A", LINEAR_A),
+    ("Linear_B", LINEAR_B),
+    ("Lisu", LISU),
+    ("Lycian", LYCIAN),
+    ("Lydian", LYDIAN),
+    ("Mahajani", MAHAJANI),
+    ("Makasar", MAKASAR),
+    ("Malayalam", MALAYALAM),
+    ("Mandaic", MANDAIC),
+    ("Manichaean", MANICHAEAN),
+    ("Marchen", MARCHEN),
+    ("Masaram_Gondi", MASARAM_GONDI),
+    ("Medefaidrin", MEDEFAIDRIN),
+    ("Meetei_Mayek", MEETEI_MAYEK),
+    ("Mende_Kikakui", MENDE_KIKAKUI),
+    ("Meroitic_Cursive", MEROITIC_CURSIVE),
+    ("Meroitic_Hieroglyphs", MEROITIC_HIEROGLYPHS),
+    ("Miao", MIAO),
+    ("Modi", MODI),
+    ("Mongolian", MONGOLIAN),
+    ("Mro", MRO),
+    ("Multani", MULTANI),
+    ("Myanmar", MYANMAR),
+    ("Nabataean", NABATAEAN),
+    ("Nag_Mundari", NAG_MUNDARI),
+    ("Nandinagari", NANDINAGARI),
+    ("New_Tai_Lue", NEW_TAI_LUE),
+    ("Newa", NEWA),
+    ("Nko", NKO),
+    ("Nushu", NUSHU),
+    ("Nyiakeng_Puachue_Hmong", NYIAKENG_PUACHUE_HMONG),
+    ("Ogham", OGHAM),
+    ("Ol_Chiki", OL_CHIKI),
+    ("Ol_Onal", OL_ONAL),
+    ("Old_Hungarian", OLD_HUNGARIAN),
+    ("Old_Italic", OLD_ITALIC),
+    ("Old_North_Arabian", OLD_NORTH_ARABIAN),
+    ("Old_Permic", OLD_PERMIC),
+    ("Old_Persian", OLD_PERSIAN),
+    ("Old_Sogdian", OLD_SOGDIAN),
+    ("Old_South_Arabian", OLD_SOUTH_ARABIAN),
+    ("Old_Turkic", OLD_TURKIC),
+    ("Old_Uyghur", OLD_UYGHUR),
+    ("Oriya", ORIYA),
+    ("Osage", OSAGE),
+    ("Osmanya", OSMANYA),
+    ("Pahawh_Hmong", PAHAWH_HMONG),
+    ("Palmyrene", PALMYRENE),
+    ("Pau_Cin_Hau", PAU_CIN_HAU),
+    ("Phags_Pa", PHAGS_PA),
+    ("Phoenician", PHOENICIAN),
+    ("Psalter_Pahlavi", PSALTER_PAHLAVI),
+    ("Rejang", REJANG),
+    ("Runic", RUNIC),
+    ("Samaritan", SAMARITAN),
+    ("Saurashtra", SAURASHTRA),
+    ("Sharada", SHARADA),
+    ("Shavian", SHAVIAN),
+    ("Siddham", SIDDHAM),
+    ("Sidetic", SIDETIC),
+    ("SignWriting", SIGNWRITING),
+    ("Sinhala", SINHALA),
+    ("Sogdian", SOGDIAN),
+    ("Sora_Sompeng", SORA_SOMPENG),
+    ("Soyombo", SOYOMBO),
+    ("Sundanese", SUNDANESE),
+    ("Sunuwar", SUNUWAR),
+    ("Syloti_Nagri", SYLOTI_NAGRI),
+    ("Syriac", SYRIAC),
+    ("Tagalog", TAGALOG),
+    ("Tagbanwa", TAGBANWA),
+    ("Tai_Le", TAI_LE),
+    ("Tai_Tham", TAI_THAM),
+    ("Tai_Viet", TAI_VIET),
+    ("Tai_Yo", TAI_YO),
+    ("Takri", TAKRI),
+    ("Tamil", TAMIL),
+    ("Tangsa", TANGSA),
+    ("Tangut", TANGUT),
+    ("Telugu", TELUGU),
+    ("Thaana", THAANA),
+    ("Thai", THAI),
+    ("Tibetan", TIBETAN),
+    ("Tifinagh", TIFINAGH),
+    ("Tirhuta", TIRHUTA),
+    ("Todhri", TODHRI),
+    ("Tolong_Siki", TOLONG_SIKI),
+    ("Toto", TOTO),
+    ("Tulu_Tigalari", TULU_TIGALARI),
+    ("Ugaritic", UGARITIC),
+    ("Vai", VAI),
+    ("Vithkuqi", VITHKUQI),
+    ("Wancho", WANCHO),
+    ("Warang_Citi", WARANG_CITI),
+    ("Yezidi", YEZIDI),
+    ("Yi", YI),
+    ("Zanabazar_Square", ZANABAZAR_SQUARE),
+];
+
+pub(crate) static BLOCKS: &[(&str, u32, u32)] = &[
+    ("Basic Latin", 0x0, 0x7f),
+    ("Latin-1 Supplement", 0x80, 0xff),
+    ("Latin Extended-A", 0x100, 0x17f),
+    ("Latin Extended-B", 0x180, 0x24f),
+    ("IPA Extensions", 0x250, 0x2af),
+    ("Spacing Modifier Letters", 0x2b0, 0x2ff),
+    ("Combining Diacritical Marks", 0x300, 0x36f),
+    ("Greek and Coptic", 0x370, 0x3ff),
+    ("Cyrillic", 0x400, 0x4ff),
+    ("Cyrillic Supplement", 0x500, 0x52f),
+    ("Armenian", 0x530, 0x58f),
+    ("Hebrew", 0x590, 0x5ff),
+    ("Arabic", 0x600, 0x6ff),
+    ("Syriac", 0x700, 0x74f),
+    ("Arabic Supplement", 0x750, 0x77f),
+    ("Thaana", 0x780, 0x7bf),
+    ("NKo", 0x7c0, 0x7ff),
+    ("Samaritan", 0x800, 0x83f),
+    ("Mandaic", 0x840, 0x85f),
+    ("Syriac Supplement", 0x860, 0x86f),
+    ("Arabic Extended-B", 0x870, 0x89f),
+    ("Arabic Extended-A", 0x8a0, 0x8ff),
+    ("Devanagari", 0x900, 0x97f),
+    ("Bengali", 0x980, 0x9ff),
+    ("Gurmukhi", 0xa00, 0xa7f),
+    ("Gujarati", 0xa80, 0xaff),
+    ("Oriya", 0xb00, 0xb7f),
+    ("Tamil", 0xb80, 0xbff),
+    ("Telugu", 0xc00, 0xc7f),
+    ("Kannada", 0xc80, 0xcff),
+    ("Malayalam", 0xd00, 0xd7f),
+    ("Sinhala", 0xd80, 0xdff),
+    ("Thai", 0xe00, 0xe7f),
+    ("Lao", 0xe80, 0xeff),
+    ("Tibetan", 0xf00, 0xfff),
+    ("Myanmar", 0x1000, 0x109f),
+    ("Georgian", 0x10a0, 0x10ff),
+    ("Hangul Jamo", 0x1100, 0x11ff),
+    ("Ethiopic", 0x1200, 0x137f),
+    ("Ethiopic Supplement", 0x1380, 0x139f),
+    ("Cherokee", 0x13a0, 0x13ff),
+    ("Unified Canadian Aboriginal Syllabics", 0x1400, 0x167f),
+    ("Ogham", 0x1680, 0x169f),
+    ("Runic", 0x16a0, 0x16ff),
+    ("Tagalog", 0x1700, 0x171f),
+    ("Hanunoo", 0x1720, 0x173f),
+    ("Buhid", 0x1740, 0x175f),
+    ("Tagbanwa", 0x1760, 0x177f),
+    ("Khmer", 0x1780, 0x17ff),
+    ("Mongolian", 0x1800, 0x18af),
+    ("Limbu", 0x1900, 0x194f),
+    ("Tai Le", 0x1950, 0x197f),
+    ("New Tai Lue", 0x1980, 0x19df),
+    ("Khmer Symbols", 0x19e0, 0x19ff),
+    ("Buginese", 0x1a00, 0x1a1f),
+    ("Tai Tham", 0x1a20, 0x1aaf),
+    ("Combining Diacritical Marks Extended", 0x1ab0, 0x1aff),
+    ("Balinese", 0x1b00, 0x1b7f),
+    ("Sundanese", 0x1b80, 0x1bbf),
+    ("Batak", 0x1bc0, 0x1bff),
+    ("Lepcha", 0x1c00, 0x1c4f),
+    ("Ol Chiki", 0x1c50, 0x1c7f),
+    ("Cyrillic Extended-C", 0x1c80, 0x1c8f),
+    ("Georgian Extended", 0x1c90, 0x1cbf),
+    ("Sundanese Supplement", 0x1cc0, 0x1ccf),
+    ("Vedic Extensions", 0x1cd0, 0x1cff),
+    ("Phonetic Extensions", 0x1d00, 0x1d7f),
+    ("Phonetic Extensions Supplement", 0x1d80, 0x1dbf),
+    ("Combining Diacritical Marks Supplement", 0x1dc0, 0x1dff),
+    ("Latin Extended Additional", 0x1e00, 0x1eff),
+    ("Greek Extended", 0x1f00, 0x1fff),
+    ("General Punctuation", 0x2000, 0x206f),
+    ("Superscripts and Subscripts", 0x2070, 0x209f),
+    ("Currency Symbols", 0x20a0, 0x20cf),
+    ("Combining Diacritical Marks for Symbols", 0x20d0, 0x20ff),
+    ("Letterlike Symbols", 0x2100, 0x214f),
+    ("Number Forms", 0x2150, 0x218f),
+    ("Arrows", 0x2190, 0x21ff),
+    ("Mathematical Operators", 0x2200, 0x22ff),
+    ("Miscellaneous Technical", 0x2300, 0x23ff),
+    ("Control Pictures", 0x2400, 0x243f),
+    ("Optical Character Recognition", 0x2440, 0x245f),
+    ("Enclosed Alphanumerics", 0x2460, 0x24ff),
+    ("Box Drawing", 0x2500, 0x257f),
+    ("Block Elements", 0x2580, 0x259f),
+    ("Geometric Shapes", 0x25a0, 0x25ff),
+    ("Miscellaneous Symbols", 0x2600, 0x26ff),
+    ("Dingbats", 0x2700, 0x27bf),
+    ("Miscellaneous Mathematical Symbols-A", 0x27c0, 0x27ef),
+    ("Supplemental Arrows-A", 0x27f0, 0x27ff),
+    ("Braille Patterns", 0x2800, 0x28ff),
+    ("Supplemental Arrows-B", 0x2900, 0x297f),
+    ("Miscellaneous Mathematical Symbols-B", 0x2980, 0x29ff),
+    ("Supplemental Mathematical Operators", 0x2a00, 0x2aff),
+    ("Miscellaneous Symbols and Arrows", 0x2b00, 0x2bff),
+    ("Glagolitic", 0x2c00, 0x2c5f),
+    ("Latin Extended-C", 0x2c60, 0x2c7f),
+    ("Coptic", 0x2c80, 0x2cff),
+    ("Georgian Supplement", 0x2d00, 0x2d2f),
+    ("Tifinagh", 0x2d30, 0x2d7f),
+    ("Ethiopic Extended", 0x2d80, 0x2ddf),
+    ("Cyrillic Extended-A", 0x2de0, 0x2dff),
+    ("Supplemental Punctuation", 0x2e00, 0x2e7f),
+    ("CJK Radicals Supplement", 0x2e80, 0x2eff),
+    ("Kangxi Radicals", 0x2f00, 0x2fdf),
+    ("Ideographic Description Characters", 0x2ff0, 0x2fff),
+    ("CJK Symbols and Punctuation", 0x3000, 0x303f),
+    ("Hiragana", 0x3040, 0x309f),
+    ("Katakana", 0x30a0, 0x30ff),
+    ("Bopomofo", 0x3100, 0x312f),
+    ("Hangul Compatibility Jamo", 0x3130, 0x318f),
+    ("Kanbun", 0x3190, 0x319f),
+    ("Bopomofo Extended", 0x31a0, 0x31bf),
+    ("CJK Strokes", 0x31c0, 0x31ef),
+    ("Katakana Phonetic Extensions", 0x31f0, 0x31ff),
+    ("Enclosed CJK Letters and Months", 0x3200, 0x32ff),
+    ("CJK Compatibility", 0x3300, 0x33ff),
+    ("CJK Unified Ideographs Extension A", 0x3400, 0x4dbf),
+    ("Yijing Hexagram Symbols", 0x4dc0, 0x4dff),
+    ("CJK Unified Ideographs", 0x4e00, 0x9fff),
+    ("Yi Syllables", 0xa000, 0xa48f),
+    ("Yi Radicals", 0xa490, 0xa4cf),
+    ("Lisu", 0xa4d0, 0xa4ff),
+    ("Vai", 0xa500, 0xa63f),
+    ("Cyrillic Extended-B", 0xa640, 0xa69f),
+    ("Bamum", 0xa6a0, 0xa6ff),
+    ("Modifier Tone Letters", 0xa700, 0xa71f),
+    ("Latin Extended-D", 0xa720, 0xa7ff),
+    ("Syloti Nagri", 0xa800, 0xa82f),
+    ("Common Indic Number Forms", 0xa830, 0xa83f),
+    ("Phags-pa", 0xa840, 0xa87f),
+    ("Saurashtra", 0xa880, 0xa8df),
+    ("Devanagari Extended", 0xa8e0, 0xa8ff),
+    ("Kayah Li", 0xa900, 0xa92f),
+    ("Rejang", 0xa930, 0xa95f),
+    ("Hangul Jamo Extended-A", 0xa960, 0xa97f),
+    ("Javanese", 0xa980, 0xa9df),
+    ("Myanmar Extended-B", 0xa9e0, 0xa9ff),
+    ("Cham", 0xaa00, 0xaa5f),
+    ("Myanmar Extended-A", 0xaa60, 0xaa7f),
+    ("Tai Viet", 0xaa80, 0xaadf),
+    ("Meetei Mayek Extensions", 0xaae0, 0xaaff),
+    ("Ethiopic Extended-A", 0xab00, 0xab2f),
+    ("Latin Extended-E", 0xab30, 0xab6f),
+    ("Cherokee Supplement", 0xab70, 0xabbf),
+    ("Meetei Mayek", 0xabc0, 0xabff),
+    ("Hangul Syllables", 0xac00, 0xd7af),
+    ("Hangul Jamo Extended-B", 0xd7b0, 0xd7ff),
+    ("High Surrogates", 0xd800, 0xdb7f),
+    ("High Private Use Surrogates", 0xdb80, 0xdbff),
+    ("Low Surrogates", 0xdc00, 0xdfff),
+    ("Private Use Area", 0xe000, 0xf8ff),
+    ("CJK Compatibility Ideographs", 0xf900, 0xfaff),
+    ("Alphabetic Presentation Forms", 0xfb00, 0xfb4f),
+    ("Arabic Presentation Forms-A", 0xfb50, 0xfdff),
+    ("Variation Selectors", 0xfe00, 0xfe0f),
+    ("Vertical Forms", 0xfe10, 0xfe1f),
+    ("Combining Half Marks", 0xfe20, 0xfe2f),
+    ("CJK Compatibility Forms", 0xfe30, 0xfe4f),
+    ("Small Form Variants", 0xfe50, 0xfe6f),
+    ("Arabic Presentation Forms-B", 0xfe70, 0xfeff),
+    ("Halfwidth and Fullwidth Forms", 0xff00, 0xffef),
+    ("Specials", 0xfff0, 0xffff),
+    ("Linear B Syllabary", 0x10000, 0x1007f),
+    ("Linear B Ideograms", 0x10080, 0x100ff),
+    ("Aegean Numbers", 0x10100, 0x1013f),
+    ("Ancient Greek Numbers", 0x10140, 0x1018f),
+    ("Ancient Symbols", 0x10190, 0x101cf),
+    ("Phaistos Disc", 0x101d0, 0x101ff),
+    ("Lycian", 0x10280, 0x1029f),
+    ("Carian", 0x102a0, 0x102df),
+    ("Coptic Epact Numbers", 0x102e0, 0x102ff),
+    ("Old Italic", 0x10300, 0x1032f),
+    ("Gothic", 0x10330, 0x1034f),
+    ("Old Permic", 0x10350, 0x1037f),
+    ("Ugaritic", 0x10380, 0x1039f),
+    ("Old Persian", 0x103a0, 0x103df),
+    ("Deseret", 0x10400, 0x1044f),
+    ("Shavian", 0x10450, 0x1047f),
+    ("Osmanya", 0x10480, 0x104af),
+    ("Osage", 0x104b0, 0x104ff),
+    ("Elbasan", 0x10500, 0x1052f),
+    ("Caucasian Albanian", 0x10530, 0x1056f),
+    ("Vithkuqi", 0x10570, 0x105bf),
+    ("Todhri", 0x105c0, 0x105ff),
+    ("Linear A", 0x10600, 0x1077f),
+    ("Latin Extended-F", 0x10780, 0x107bf),
+    ("Cypriot Syllabary", 0x10800, 0x1083f),
+    ("Imperial Aramaic", 0x10840, 0x1085f),
+    ("Palmyrene", 0x10860, 0x1087f),
+    ("Nabataean", 0x10880, 0x108af),
+    ("Hatran", 0x108e0, 0x108ff),
+    ("Phoenician", 0x10900, 0x1091f),
+    ("Lydian", 0x10920, 0x1093f),
+    ("Sidetic", 0x10940, 0x1095f),
+    ("Meroitic Hieroglyphs", 0x10980, 0x1099f),
+    ("Meroitic Cursive", 0x109a0, 0x109ff),
+    ("Kharoshthi", 0x10a00, 0x10a5f),
+    ("Old South Arabian", 0x10a60, 0x10a7f),
+    ("Old North Arabian", 0x10a80, 0x10a9f),
+    ("Manichaean", 0x10ac0, 0x10aff),
+    ("Avestan", 0x10b00, 0x10b3f),
+    ("Inscriptional Parthian", 0x10b40, 0x10b5f),
+    ("Inscriptional Pahlavi", 0x10b60, 0x10b7f),
+    ("Psalter Pahlavi", 0x10b80, 0x10baf),
+    ("Old Turkic", 0x10c00, 0x10c4f),
+    ("Old Hungarian", 0x10c80, 0x10cff),
+    ("Hanifi Rohingya", 0x10d00, 0x10d3f),
+    ("Garay", 0x10d40, 0x10d8f),
+    ("Rumi Numeral Symbols", 0x10e60, 0x10e7f),
+    ("Yezidi", 0x10e80, 0x10ebf),
+    ("Arabic Extended-C", 0x10ec0, 0x10eff),
+    ("Old Sogdian", 0x10f00, 0x10f2f),
+    ("Sogdian", 0x10f30, 0x10f6f),
+    ("Old Uyghur", 0x10f70, 0x10faf),
+    ("Chorasmian", 0x10fb0, 0x10fdf),
+    ("Elymaic", 0x10fe0, 0x10fff),
+    ("Brahmi", 0x11000, 0x1107f),
+    ("Kaithi", 0x11080, 0x110cf),
+    ("Sora Sompeng", 0x110d0, 0x110ff),
+    ("Chakma", 0x11100, 0x1114f),
+    ("Mahajani", 0x11150, 0x1117f),
+    ("Sharada", 0x11180, 0x111df),
+    ("Sinhala Archaic Numbers", 0x111e0, 0x111ff),
+    ("Khojki", 0x11200, 0x1124f),
+    ("Multani", 0x11280, 0x112af),
+    ("Khudawadi", 0x112b0, 0x112ff),
+    ("Grantha", 0x11300, 0x1137f),
+    ("Tulu-Tigalari", 0x11380, 0x113ff),
+    ("Newa", 0x11400, 0x1147f),
+    ("Tirhuta", 0x11480, 0x114df),
+    ("Siddham", 0x11580, 0x115ff),
+    ("Modi", 0x11600, 0x1165f),
+    ("Mongolian Supplement", 0x11660, 0x1167f),
+    ("Takri", 0x11680, 0x116cf),
+    ("Myanmar Extended-C", 0x116d0, 0x116ff),
+    ("Ahom", 0x11700, 0x1174f),
+    ("Dogra", 0x11800, 0x1184f),
+    ("Warang Citi", 0x118a0, 0x118ff),
+    ("Dives Akuru", 0x11900, 0x1195f),
+    ("Nandinagari", 0x119a0, 0x119ff),
+    ("Zanabazar Square", 0x11a00, 0x11a4f),
+    ("Soyombo", 0x11a50, 0x11aaf),
+    ("Pau Cin Hau", 0x11ac0, 0x11aff),
+    ("Devanagari Extended-A", 0x11b00, 0x11b5f),
+    ("Sharada Supplement", 0x11b60, 0x11b7f),
+    ("Sunuwar", 0x11bc0, 0x11bff),
+    ("Bhaiksuki", 0x11c00, 0x11c6f),
+    ("Marchen", 0x11c70, 0x11cbf),
+    ("Masaram Gondi", 0x11d00, 0x11d5f),
+    ("Gunjala Gondi", 0x11d60, 0x11daf),
+    ("Tolong Siki", 0x11db0, 0x11def),
+    ("Makasar", 0x11ee0, 0x11eff),
+    ("Kawi", 0x11f00, 0x11f5f),
+    ("Lisu Supplement", 0x11fb0, 0x11fbf),
+    ("Tamil Supplement", 0x11fc0, 0x11fff),
+    ("Cuneiform", 0x12000, 0x123ff),
+    ("Cuneiform Numbers and Punctuation", 0x12400, 0x1247f),
+    ("Early Dynastic Cuneiform", 0x12480, 0x1254f),
+    ("Cypro-Minoan", 0x12f90, 0x12fff),
+    ("Egyptian Hieroglyphs", 0x13000, 0x1342f),
+    ("Egyptian Hieroglyph Format Controls", 0x13430, 0x1345f),
+    ("Egyptian Hieroglyphs Extended-A", 0x13460, 0x143ff),
+    ("Anatolian Hieroglyphs", 0x14400, 0x1467f),
+    ("Gurung Khema", 0x16100, 0x1613f),
+    ("Bamum Supplement", 0x16800, 0x16a3f),
+    ("Mro", 0x16a40, 0x16a6f),
+    ("Tangsa", 0x16a70, 0x16acf),
+    ("Bassa Vah", 0x16ad0, 0x16aff),
+    ("Pahawh Hmong", 0x16b00, 0x16b8f),
+    ("Kirat Rai", 0x16d40, 0x16d7f),
+    ("Medefaidrin", 0x16e40, 0x16e9f),
+    ("Beria Erfe", 0x16ea0, 0x16edf),
+    ("Miao", 0x16f00, 0x16f9f),
+    ("Ideographic Symbols and Punctuation", 0x16fe0, 0x16fff),
+    ("Tangut", 0x17000, 0x187ff),
+    ("Tangut Components", 0x18800, 0x18aff),
+    ("Khitan Small Script", 0x18b00, 0x18cff),
+    ("Tangut Supplement", 0x18d00, 0x18d7f),
+    ("Tangut Components Supplement", 0x18d80, 0x18dff),
+    ("Kana Extended-B", 0x1aff0, 0x1afff),
+    ("Kana Supplement", 0x1b000, 0x1b0ff),
+    ("Kana Extended-A", 0x1b100, 0x1b12f),
+    ("Small Kana Extension", 0x1b130, 0x1b16f),
+    ("Nushu", 0x1b170, 0x1b2ff),
+    ("Duployan", 0x1bc00, 0x1bc9f),
+    ("Shorthand Format Controls", 0x1bca0, 0x1bcaf),
+    ("Symbols for Legacy Computing Supplement", 0x1cc00, 0x1cebf),
+    ("Miscellaneous Symbols Supplement", 0x1cec0, 0x1ceff),
+    ("Znamenny Musical Notation", 0x1cf00, 0x1cfcf),
+    ("Byzantine Musical Symbols", 0x1d000, 0x1d0ff),
+    ("Musical Symbols", 0x1d100, 0x1d1ff),
+    ("Ancient Greek Musical Notation", 0x1d200, 0x1d24f),
+    ("Kaktovik Numerals", 0x1d2c0, 0x1d2df),
+    ("Mayan Numerals", 0x1d2e0, 0x1d2ff),
+    ("Tai Xuan Jing Symbols", 0x1d300, 0x1d35f),
+    ("Counting Rod Numerals", 0x1d360, 0x1d37f),
+    ("Mathematical Alphanumeric Symbols", 0x1d400, 0x1d7ff),
+    ("Sutton SignWriting", 0x1d800, 0x1daaf),
+    ("Latin Extended-G", 0x1df00, 0x1dfff),
+    ("Glagolitic Supplement", 0x1e000, 0x1e02f),
+    ("Cyrillic Extended-D", 0x1e030, 0x1e08f),
+    ("Nyiakeng Puachue Hmong", 0x1e100, 0x1e14f),
+    ("Toto", 0x1e290, 0x1e2bf),
+    ("Wancho", 0x1e2c0, 0x1e2ff),
+    ("Nag Mundari", 0x1e4d0, 0x1e4ff),
+    ("Ol Onal", 0x1e5d0, 0x1e5ff),
+    ("Tai Yo", 0x1e6c0, 0x1e6ff),
+    ("Ethiopic Extended-B", 0x1e7e0, 0x1e7ff),
+    ("Mende Kikakui", 0x1e800, 0x1e8df),
+    ("Adlam", 0x1e900, 0x1e95f),
+    ("Indic Siyaq Numbers", 0x1ec70, 0x1ecbf),
+    ("Ottoman Siyaq Numbers", 0x1ed00, 0x1ed4f),
+    ("Arabic Mathematical Alphabetic Symbols", 0x1ee00, 0x1eeff),
+    ("Mahjong Tiles", 0x1f000, 0x1f02f),
+    ("Domino Tiles", 0x1f030, 0x1f09f),
+    ("Playing Cards", 0x1f0a0, 0x1f0ff),
+    ("Enclosed Alphanumeric Supplement", 0x1f100, 0x1f1ff),
+    ("Enclosed Ideographic Supplement", 0x1f200, 0x1f2ff),
+    ("Miscellaneous Symbols and Pictographs", 0x1f300, 0x1f5ff),
+    ("Emoticons", 0x1f600, 0x1f64f),
+    ("Ornamental Dingbats", 0x1f650, 0x1f67f),
+    ("Transport and Map Symbols", 0x1f680, 0x1f6ff),
+    ("Alchemical Symbols", 0x1f700, 0x1f77f),
+    ("Geometric Shapes Extended", 0x1f780, 0x1f7ff),
+    ("Supplemental Arrows-C", 0x1f800, 0x1f8ff),
+    ("Supplemental Symbols and Pictographs", 0x1f900, 0x1f9ff),
+    ("Chess Symbols", 0x1fa00, 0x1fa6f),
+    ("Symbols and Pictographs Extended-A", 0x1fa70, 0x1faff),
+    ("Symbols for Legacy Computing", 0x1fb00, 0x1fbff),
+    ("CJK Unified Ideographs Extension B", 0x20000, 0x2a6df),
+    ("CJK Unified Ideographs Extension C", 0x2a700, 0x2b73f),
+    ("CJK Unified Ideographs Extension D", 0x2b740, 0x2b81f),
+    ("CJK Unified Ideographs Extension E", 0x2b820, 0x2ceaf),
+    ("CJK Unified Ideographs Extension F", 0x2ceb0, 0x2ebef),
+    ("CJK Unified Ideographs Extension I", 0x2ebf0, 0x2ee5f),
+    ("CJK Compatibility Ideographs Supplement", 0x2f800, 0x2fa1f),
+    ("CJK Unified Ideographs Extension G", 0x30000, 0x3134f),
+    ("CJK Unified Ideographs Extension H", 0x31350, 0x323af),
+    ("CJK Unified Ideographs Extension J", 0x323b0, 0x3347f),
+    ("Tags", 0xe0000, 0xe007f),
+    ("Variation Selectors Supplement", 0xe0100, 0xe01ef),
+    ("Supplementary Private Use Area-A", 0xf0000, 0xfffff),
+    ("Supplementary Private Use Area-B", 0x100000, 0x10ffff),
+];
@@ -0,0 +1,50 @@
+//! Tests for the ASCII bitmap cache of the `cache` feature
+#![cfg(feature = "cache")]
+use charclass::{charclass, CharClass};
+
+#[test]
+fn mutation_resets_cache() {
+    let mut ccl = charclass!['a' => 'c'];
+
+    // Populate the cache by an ASCII lookup
+    assert!(ccl.contains('b'));
+    assert!(!ccl.contains('x'));
+
+    ccl.add('x'..='z');
+    assert!(ccl.contains('x'));
+
+    ccl.remove('a'..='b');
+    assert!(!ccl.contains('a'));
+    assert!(ccl.contains('c'));
+
+    ccl.extend_sorted(['{'..='~']);
+    assert!(ccl.contains('~'));
+
+    ccl.clamp('x'..='~');
+    assert!(!ccl.contains('c'));
+
+    ccl.clear();
+    assert!(!ccl.contains('x'));
+
+    ccl += 'q';
+    assert!(ccl.contains('q'));
+}
+
+#[test]
+fn cache_is_ignored_by_eq_and_hash() {
+    use std::collections::HashSet;
+
+    let a = charclass!['a' => 'z'];
+    let b = charclass!['a' => 'z'];
+    assert!(a.contains('q'));
+
+    assert_eq!(a, b);
+    assert_eq!(HashSet::from([a.clone(), b]).len(), 1);
+
+    // Clones carry a valid cache along
+    let mut c = a.clone();
+    assert!(c.contains('q'));
+    c -= CharClass::single('q');
+    assert!(!c.contains('q'));
+    assert!(a.contains('q'));
+}
@@ -0,0 +1,230 @@
+//! Randomized tests comparing CharClass against brute-force models
+use charclass::CharClass;
+use std::collections::BTreeSet;
+
+/// Minimal xorshift PRNG, to keep the tests free of dependencies
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Random char from a small universe, to keep overlaps likely
+    fn char(&mut self) -> char {
+        char::from_u32(0x20 + (self.next() % 0x100) as u32).unwrap()
+    }
+
+    fn range(&mut self) -> std::ops::RangeInclusive<char> {
+        let a = self.char();
+        let b = self.char();
+
+        if a <= b {
+            a..=b
+        } else {
+            b..=a
+        }
+    }
+}
+
+#[test]
+fn test_matches_brute_force() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..200 {
+        let mut ccl = CharClass::new();
+        let mut model = Vec::new();
+
+        for _ in 0..(rng.next() % 8) {
+            let range = rng.range();
+            ccl.add(range.clone());
+            model.push(range);
+        }
+
+        for _ in 0..50 {
+            let query = rng.range();
+            let expected = query
+                .clone()
+                .all(|ch| model.iter().any(|r| r.contains(&ch)));
+
+            assert_eq!(ccl.test(&query), expected, "{:?} {:?}", ccl, query);
+        }
+    }
+}
+
+#[test]
+fn sub_matches_brute_force() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+    for _ in 0..200 {
+        let mut a = CharClass::new();
+        let mut b = CharClass::new();
+
+        for _ in 0..(rng.next() % 8) {
+            a.add(rng.range());
+        }
+
+        for _ in 0..(rng.next() % 8) {
+            b.add(rng.range());
+        }
+
+        let diff = a.clone() - b.clone();
+
+        for ch in '\0'..='\u{200}' {
+            let range = ch..=ch;
+            assert_eq!(
+                diff.test(&range),
+                a.test(&range) && !b.test(&range),
+                "{:?} - {:?} = {:?}",
+                a,
+                b,
+                diff
+            );
+        }
+    }
+}
+
+#[test]
+fn and_matches_brute_force() {
+    let mut rng = Rng(0x85eb_ca6b_c2b2_ae35);
+
+    for _ in 0..200 {
+        let mut a = CharClass::new();
+        let mut b = CharClass::new();
+
+        for _ in 0..(rng.next() % 8) {
+            a.add(rng.range());
+        }
+
+        for _ in 0..(rng.next() % 8) {
+            b.add(rng.range());
+        }
+
+        let both = a.clone() & b.clone();
+        assert_eq!(both, CharClass::intersect_iter([&a, &b]));
+
+        for ch in '\0'..='\u{200}' {
+            assert_eq!(
+                both.contains(ch),
+                a.contains(ch) && b.contains(ch),
+                "{:?} & {:?}",
+                a,
+                b
+            );
+        }
+    }
+}
+
+#[test]
+fn negate_matches_difference() {
+    let mut rng = Rng(0xd1b5_4a32_d192_ed03);
+    let edges = [
+        '\0',
+        '\u{D7FE}',
+        '\u{D7FF}',
+        '\u{E000}',
+        '\u{E001}',
+        char::MAX,
+    ];
+
+    for _ in 0..200 {
+        let mut ccl = CharClass::new();
+
+        for _ in 0..(rng.next() % 8) {
+            ccl.add(rng.range());
+        }
+
+        // Occasionally touch the surrogate gap and the bounds of the char range
+        for _ in 0..(rng.next() % 3) {
+            let a = edges[(rng.next() % edges.len() as u64) as usize];
+            let b = edges[(rng.next() % edges.len() as u64) as usize];
+            ccl.add(a.min(b)..=a.max(b));
+        }
+
+        let negated = ccl.clone().negate();
+        assert_eq!(negated, CharClass::any() - ccl.clone(), "{:?}", ccl);
+        assert_eq!(negated.clone().negate(), ccl);
+
+        for ch in ('\0'..='\u{200}').chain(edges) {
+            let range = ch..=ch;
+            assert_eq!(
+                negated.test(&range),
+                !ccl.test(&range),
+                "{:?} {:?}",
+                ccl,
+                ch
+            );
+        }
+    }
+}
+
+/// Characters the set operations are checked on: small universe plus the edges
+fn universe() -> impl Iterator<Item = char> {
+    ('\0'..='\u{200}').chain(['\u{D7FE}', '\u{D7FF}', '\u{E000}', '\u{E001}', char::MAX])
+}
+
+#[test]
+fn set_ops_match_btreeset_model() {
+    let mut rng = Rng(0xc2b2_ae3d_27d4_eb4f);
+
+    // The model is built from the generated ranges, never through CharClass
+    let random_class = |rng: &mut Rng| {
+        let mut ccl = CharClass::new();
+        let mut set = BTreeSet::new();
+
+        for _ in 0..(rng.next() % 6) {
+            let range = rng.range();
+            let (start, end) = (*range.start() as u32, *range.end() as u32);
+
+            set.extend((start..=end).filter_map(char::from_u32));
+            ccl.add(range);
+        }
+
+        (ccl, set)
+    };
+
+    let model_of =
+        |ccl: &CharClass| -> BTreeSet<char> { universe().filter(|ch| ccl.contains(*ch)).collect() };
+
+    for _ in 0..100 {
+        let (mut ccl, mut model) = random_class(&mut rng);
+
+        for _ in 0..20 {
+            let (other, set) = random_class(&mut rng);
+
+            let op = rng.next() % 5;
+            match op {
+                0 => {
+                    ccl += other;
+                    model = &model | &set;
+                }
+                1 => {
+                    ccl -= other;
+                    model = &model - &set;
+                }
+                2 => {
+                    ccl &= other;
+                    model = &model & &set;
+                }
+                3 => {
+                    ccl ^= other;
+                    model = &model ^ &set;
+                }
+                _ => {
+                    ccl = ccl.negate();
+                    model = universe().filter(|ch| !model.contains(ch)).collect();
+                }
+            }
+
+            // A normalized class is left unchanged by normalizing it again
+            let mut normalized = ccl.clone();
+            normalized.normalize();
+            assert_eq!(normalized, ccl, "not normalized after op {}", op);
+
+            assert_eq!(model_of(&ccl), model, "{:?} after op {}", ccl, op);
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! Tests for the normalization assertions of debug builds
+use charclass::CharClass;
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "is_normalized")]
+fn corrupted_class_trips_assertion() {
+    let mut ccl = CharClass::from_ranges_unchecked(vec!['a'..='m', 'f'..='z']);
+    ccl.remove('b'..='b');
+}
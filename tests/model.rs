@@ -0,0 +1,116 @@
+//! Model-based property tests, comparing CharClass against a BTreeSet<char>
+use charclass::CharClass;
+use proptest::prelude::*;
+use std::collections::BTreeSet;
+
+/// Characters around the surrogate gap and the end of the char range
+const EDGES: [char; 5] = ['\u{D7FE}', '\u{D7FF}', '\u{E000}', '\u{E001}', char::MAX];
+
+/// The universe of the model: 0..=0x3FF plus the edges
+fn universe() -> impl Iterator<Item = char> {
+    ('\0'..='\u{3FF}').chain(EDGES)
+}
+
+fn any_char() -> impl Strategy<Value = char> {
+    prop_oneof![
+        9 => (0u32..=0x3ff).prop_map(|cp| char::from_u32(cp).unwrap()),
+        1 => proptest::sample::select(EDGES.to_vec()),
+    ]
+}
+
+fn any_range() -> impl Strategy<Value = std::ops::RangeInclusive<char>> {
+    (any_char(), any_char()).prop_map(|(a, b)| a.min(b)..=a.max(b))
+}
+
+/// A class, together with the ranges it was built from
+fn any_class() -> impl Strategy<Value = (Vec<std::ops::RangeInclusive<char>>, CharClass)> {
+    proptest::collection::vec(any_range(), 0..6).prop_map(|ranges| {
+        let mut ccl = CharClass::new();
+
+        for range in &ranges {
+            ccl.add(range.clone());
+        }
+
+        (ranges, ccl)
+    })
+}
+
+#[derive(Debug, Clone)]
+enum Op {
+    Add(std::ops::RangeInclusive<char>),
+    Remove(std::ops::RangeInclusive<char>),
+    Union(Vec<std::ops::RangeInclusive<char>>, CharClass),
+    Intersect(Vec<std::ops::RangeInclusive<char>>, CharClass),
+    Difference(Vec<std::ops::RangeInclusive<char>>, CharClass),
+    Negate,
+    Contains(char),
+}
+
+fn any_op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        any_range().prop_map(Op::Add),
+        any_range().prop_map(Op::Remove),
+        any_class().prop_map(|(ranges, ccl)| Op::Union(ranges, ccl)),
+        any_class().prop_map(|(ranges, ccl)| Op::Intersect(ranges, ccl)),
+        any_class().prop_map(|(ranges, ccl)| Op::Difference(ranges, ccl)),
+        Just(Op::Negate),
+        any_char().prop_map(Op::Contains),
+    ]
+}
+
+/// The members of `ccl` within the universe
+fn model_of(ccl: &CharClass) -> BTreeSet<char> {
+    universe().filter(|ch| ccl.contains(*ch)).collect()
+}
+
+/// The members of `range` within the universe
+fn model_of_range(range: &std::ops::RangeInclusive<char>) -> BTreeSet<char> {
+    universe().filter(|ch| range.contains(ch)).collect()
+}
+
+/// The members of all `ranges` within the universe
+fn model_of_ranges(ranges: &[std::ops::RangeInclusive<char>]) -> BTreeSet<char> {
+    ranges.iter().flat_map(model_of_range).collect()
+}
+
+proptest! {
+    #[test]
+    fn operations_match_model(ops in proptest::collection::vec(any_op(), 1..30)) {
+        let mut ccl = CharClass::new();
+        let mut model = BTreeSet::new();
+
+        for op in ops {
+            match op.clone() {
+                Op::Add(range) => {
+                    model.extend(model_of_range(&range));
+                    ccl.add(range);
+                }
+                Op::Remove(range) => {
+                    model = &model - &model_of_range(&range);
+                    ccl.remove(range);
+                }
+                Op::Union(ranges, other) => {
+                    model = &model | &model_of_ranges(&ranges);
+                    ccl += other;
+                }
+                Op::Intersect(ranges, other) => {
+                    model = &model & &model_of_ranges(&ranges);
+                    ccl &= other;
+                }
+                Op::Difference(ranges, other) => {
+                    model = &model - &model_of_ranges(&ranges);
+                    ccl -= other;
+                }
+                Op::Negate => {
+                    model = universe().filter(|ch| !model.contains(ch)).collect();
+                    ccl = ccl.negate();
+                }
+                Op::Contains(ch) => {
+                    prop_assert_eq!(ccl.contains(ch), model.contains(&ch));
+                }
+            }
+
+            prop_assert_eq!(model_of(&ccl), model.clone(), "after {:?}", op);
+        }
+    }
+}